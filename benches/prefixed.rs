@@ -0,0 +1,24 @@
+//! Compares hashing a shared prefix once against hashing both halves independently.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use dualhashkey::DualHashKey;
+
+const PARENT: &[u8] = b"assets/textures/environment/forest/trees/oak";
+const FULL: &[u8] = b"assets/textures/environment/forest/trees/oak/bark_albedo.png";
+
+fn prefixed(c: &mut Criterion) {
+    let suffix = &FULL[PARENT.len()..];
+    assert_eq!(DualHashKey::from_prefixed_bytes(PARENT, suffix), DualHashKey::from_dual_bytes(PARENT, FULL));
+    
+    let mut group = c.benchmark_group("nested-path");
+    group.bench_function("from_dual_bytes", |b| {
+        b.iter(|| DualHashKey::from_dual_bytes(black_box(PARENT), black_box(FULL)))
+    });
+    group.bench_function("from_prefixed_bytes", |b| {
+        b.iter(|| DualHashKey::from_prefixed_bytes(black_box(PARENT), black_box(suffix)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, prefixed);
+criterion_main!(benches);
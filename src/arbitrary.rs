@@ -0,0 +1,16 @@
+//! [Arbitrary] implementation for [DualHashKey], for fuzzing.
+
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{DualHashKey, MIN};
+
+/// Consumes a [u64] from the input, mapping a zero to [MIN] so that a valid key is always produced.
+impl<'a> Arbitrary<'a> for DualHashKey {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::from_raw(u64::arbitrary(u)?).unwrap_or(MIN))
+    }
+    
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u64::size_hint(depth)
+    }
+}
@@ -0,0 +1,21 @@
+//! [BorshSerialize] and [BorshDeserialize] implementations for [DualHashKey].
+//! 
+//! The key is encoded as its raw [u64], in little-endian byte order.
+
+use ::borsh::io::{Error, ErrorKind, Read, Write};
+use ::borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::DualHashKey;
+
+impl BorshSerialize for DualHashKey {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        self.get_hash_raw().serialize(writer)
+    }
+}
+
+impl BorshDeserialize for DualHashKey {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self, Error> {
+        let raw = u64::deserialize_reader(reader)?;
+        Self::from_raw(raw).ok_or_else(|| Error::new(ErrorKind::InvalidData, "raw value of DHK is zero"))
+    }
+}
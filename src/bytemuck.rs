@@ -0,0 +1,25 @@
+//! [Pod] and [Zeroable] implementations for [RawDualHashKey].
+//! 
+//! [DualHashKey] itself can't be [Pod], due to its [NonZeroU64](crate::NonZeroU64) niche;
+//! bulk data is cast to [RawDualHashKey] instead, and validated on access.
+
+use ::bytemuck::{Pod, Zeroable};
+
+use crate::{DualHashKey, RawDualHashKey};
+
+// # Safety
+// RawDualHashKey is a `#[repr(transparent)]` wrapper around a `u64`,
+// so every bit-pattern (including all-zeroes) is a valid value.
+unsafe impl Zeroable for RawDualHashKey {}
+unsafe impl Pod for RawDualHashKey {}
+
+/// Functions for zero-copy bulk access to DHKs, via `bytemuck`.
+impl DualHashKey {
+    /// Validates a slice of [RawDualHashKey]s on access,
+    /// yielding `None` for every raw key that is zero.
+    /// 
+    /// Combine with [bytemuck::cast_slice] to reinterpret a byte-buffer without copying.
+    pub fn cast_slice(raw: &[RawDualHashKey]) -> impl Iterator<Item = Option<DualHashKey>> + '_ {
+        raw.iter().map(|raw| raw.finish())
+    }
+}
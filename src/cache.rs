@@ -0,0 +1,109 @@
+//! A global, thread-safe cache of high-half hashes.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock, PoisonError};
+
+use crate::{hash, DualHashKey};
+
+/// The maximum number of high-half strings kept in the global cache.
+pub const CACHE_CAPACITY: usize = 4096;
+
+/// The high-half hashes, keyed by their source string,
+/// along with the tick at which each was last used.
+struct Cache {
+    entries: HashMap<Box<str>, (u32, u64)>,
+    tick: u64
+}
+
+static CACHE: OnceLock<Mutex<Cache>> = OnceLock::new();
+
+impl Cache {
+    fn high_hash(&mut self, high: &str) -> u32 {
+        self.tick += 1;
+        if let Some((hash, last_used)) = self.entries.get_mut(high) {
+            *last_used = self.tick;
+            return *hash;
+        }
+    
+        if self.entries.len() >= CACHE_CAPACITY {
+            // Evict the least-recently used entry.
+            let oldest = self.entries.iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(high, _)| high.clone());
+            if let Some(oldest) = oldest {
+                self.entries.remove(&oldest);
+            }
+        }
+    
+        let hash = hash::fnv1a_32(high.as_bytes(), hash::FNV_OFFSET_BASIS_32);
+        self.entries.insert(high.into(), (hash, self.tick));
+        hash
+    }
+}
+
+impl DualHashKey {
+    /// Creates a new [DualHashKey] from the pair of high and low strings,
+    /// looking up the hash of the high string in a global cache shared by all threads.
+    /// 
+    /// The result is the same as with [Self::from_dual_str].
+    /// 
+    /// The cache holds up to [CACHE_CAPACITY] high strings (each stored as its own allocation),
+    /// evicting the least-recently used one when full; eviction scans the whole cache.
+    /// Since FNV-1a is cheap, this only pays off for long high strings that are used over and over.
+    pub fn cached_key(high: &str, low: &str) -> Option<Self> {
+        let cache = CACHE.get_or_init(|| Mutex::new(Cache {
+            entries: HashMap::with_capacity(CACHE_CAPACITY),
+            tick: 0
+        }));
+        let high = cache.lock().unwrap_or_else(PoisonError::into_inner).high_hash(high);
+        Self::from_raw_dual(high, hash::fnv1a_32(low.as_bytes(), hash::FNV_OFFSET_BASIS_32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::thread;
+    
+    use super::{Cache, CACHE, CACHE_CAPACITY};
+    use crate::DualHashKey;
+    
+    #[test]
+    fn concurrent_lookups_match_uncached() {
+        // Together, the threads push twice as many distinct high strings through the cache as it can hold.
+        let threads: Vec<_> = (0..4)
+            .map(|thread| thread::spawn(move || {
+                for i in 0..CACHE_CAPACITY / 2 {
+                    let hot = format!("hot/{}", i % 16);
+                    let cold = format!("cold/{thread}/{i}");
+                    for high in [&hot, &cold] {
+                        assert_eq!(DualHashKey::cached_key(high, "leaf"), DualHashKey::from_dual_str(high, "leaf"));
+                    }
+                }
+            }))
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+    
+        let cache = CACHE.get().unwrap().lock().unwrap();
+        assert!(cache.entries.len() <= CACHE_CAPACITY);
+    }
+    
+    #[test]
+    fn evicts_least_recently_used() {
+        let mut cache = Cache {entries: HashMap::new(), tick: 0};
+        for i in 0..CACHE_CAPACITY {
+            cache.high_hash(&i.to_string());
+        }
+    
+        // Touch the oldest entry, so the second-oldest is evicted instead.
+        cache.high_hash("0");
+        cache.high_hash("new");
+    
+        assert_eq!(cache.entries.len(), CACHE_CAPACITY);
+        assert!(cache.entries.contains_key("0"));
+        assert!(!cache.entries.contains_key("1"));
+        assert!(cache.entries.contains_key("new"));
+    }
+}
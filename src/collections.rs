@@ -0,0 +1,136 @@
+//! Ordered collections of [DualHashKey]s, with subtree iteration built in.
+
+use std::collections::BTreeMap;
+
+use crate::{DhkSet, DualHashKey};
+
+/// An ordered set of [DualHashKey]s; the set counterpart of [DualHashMap].
+pub type DualHashSet = DhkSet;
+
+/// An ordered map keyed by [DualHashKey]s, wrapping a [BTreeMap].
+#[derive(Clone, PartialEq, Eq)]
+pub struct DualHashMap<V> {
+    pub entries: BTreeMap<DualHashKey, V>
+}
+
+impl<V> Default for DualHashMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: core::fmt::Debug> core::fmt::Debug for DualHashMap<V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.entries.iter()).finish()
+    }
+}
+
+impl<V> DualHashMap<V> {
+    /// Creates a new empty [DualHashMap].
+    pub const fn new() -> Self {
+        Self {entries: BTreeMap::new()}
+    }
+    
+    /// Returns the number of entries in the map.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    
+    /// Returns `true` if the map contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+    
+    /// Inserts a value under the given key, returning the previous value, if any.
+    pub fn insert(&mut self, key: DualHashKey, value: V) -> Option<V> {
+        self.entries.insert(key, value)
+    }
+    
+    /// Returns a reference to the value under the given key.
+    pub fn get(&self, key: &DualHashKey) -> Option<&V> {
+        self.entries.get(key)
+    }
+    
+    /// Returns a mutable reference to the value under the given key.
+    pub fn get_mut(&mut self, key: &DualHashKey) -> Option<&mut V> {
+        self.entries.get_mut(key)
+    }
+    
+    /// Removes the value under the given key, returning it if it was present.
+    pub fn remove(&mut self, key: &DualHashKey) -> Option<V> {
+        self.entries.remove(key)
+    }
+    
+    /// Iterates over all entries in ascending key order.
+    pub fn iter(&self) -> std::collections::btree_map::Iter<'_, DualHashKey, V> {
+        self.entries.iter()
+    }
+    
+    /// Iterates over all entries whose keys share the high-half of the `parent`, in ascending key order.
+    pub fn iter_subtree(&self, parent: DualHashKey) -> std::collections::btree_map::Range<'_, DualHashKey, V> {
+        self.entries.range(parent.subtree_range())
+    }
+    
+    /// A capacity hint for inserting about `approx_children` keys under one parent.
+    /// 
+    /// This is a no-op, since the backing [BTreeMap] cannot reserve capacity;
+    /// it exists so code can keep the hint if the backing ever becomes a hash map.
+    #[inline(always)]
+    pub fn reserve_subtree(&mut self, approx_children: usize) {
+        let _ = approx_children;
+    }
+    
+    /// Returns the number of entries whose keys share the high-half of the `parent`.
+    pub fn prefix_count(&self, parent: DualHashKey) -> usize {
+        self.iter_subtree(parent).count()
+    }
+}
+
+impl<V> FromIterator<(DualHashKey, V)> for DualHashMap<V> {
+    fn from_iter<I: IntoIterator<Item = (DualHashKey, V)>>(iter: I) -> Self {
+        Self {entries: BTreeMap::from_iter(iter)}
+    }
+}
+
+impl<V> Extend<(DualHashKey, V)> for DualHashMap<V> {
+    fn extend<I: IntoIterator<Item = (DualHashKey, V)>>(&mut self, iter: I) {
+        self.entries.extend(iter)
+    }
+}
+
+impl<'a, V> IntoIterator for &'a DualHashMap<V> {
+    type Item = (&'a DualHashKey, &'a V);
+    type IntoIter = std::collections::btree_map::Iter<'a, DualHashKey, V>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.iter()
+    }
+}
+
+impl<V> IntoIterator for DualHashMap<V> {
+    type Item = (DualHashKey, V);
+    type IntoIter = std::collections::btree_map::IntoIter<DualHashKey, V>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DualHashMap;
+    use crate::DualHashKey;
+    
+    #[test]
+    fn insert_after_reserve_subtree() {
+        let parent = DualHashKey::from_high_str("root").unwrap();
+        let mut map = DualHashMap::new();
+        map.reserve_subtree(3);
+        for (i, name) in ["a", "b", "c"].into_iter().enumerate() {
+            assert_eq!(map.insert(DualHashKey::from_dual_str("root", name).unwrap(), i), None);
+        }
+        map.insert(DualHashKey::from_dual_str("other", "a").unwrap(), 3);
+    
+        assert_eq!(map.len(), 4);
+        assert_eq!(map.prefix_count(parent), 3);
+        assert_eq!(map.iter_subtree(parent).map(|(_, value)| *value).sum::<usize>(), 3);
+    }
+}
@@ -0,0 +1,183 @@
+//! An implementation of a 128-bit [DualHashKey128],
+//! for hierarchies where the 32-bit halves of a [DualHashKey](crate::DualHashKey) collide too often.
+
+pub use core::num::NonZeroU128;
+
+use crate::{hash, SEPARATOR};
+
+/// Shorthand alias for [DualHashKey128].
+pub type DHK128 = DualHashKey128;
+
+/// A mask for the low-half of a [DualHashKey128].
+/// 
+/// The maximum value of an [u64], zero-extended into a [u128].
+pub const LOW_MASK_128: u128 = u64::MAX as u128;
+
+/// A mask for the high-half of a [DualHashKey128].
+pub const HIGH_MASK_128: u128 = !(u64::MAX as u128);
+
+/// The offset of the high-half in a [DualHashKey128].
+pub const HIGH_SHIFT_128: u128 = 64;
+
+/// The lowest possible [DualHashKey128].
+pub const MIN_128: DualHashKey128 = DualHashKey128 {hash: NonZeroU128::MIN};
+
+/// The highest possible [DualHashKey128].
+pub const MAX_128: DualHashKey128 = DualHashKey128 {hash: NonZeroU128::MAX};
+
+/// A key made of two 64-bit hashes, whose raw value is never zero.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct DualHashKey128 {
+    pub hash: NonZeroU128
+}
+
+/// Hash-implementation: Writes the hash via `write_u128`. That's it.
+impl core::hash::Hash for DualHashKey128 {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        state.write_u128(self.get_hash_raw())
+    }
+}
+
+impl core::fmt::Debug for DualHashKey128 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DualHashKey128")
+            .field("hash", &self.hash)
+            .field("_high", &self.get_hash_high_half())
+            .field("_low", &self.get_hash_low_half())
+            .finish()
+    }
+}
+
+/// Displays the key as `HIGH.LOW`, with both halves as 16 uppercase hex digits.
+impl core::fmt::Display for DualHashKey128 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:0>16X}{SEPARATOR}{:0>16X}", self.get_hash_high_half(), self.get_hash_low_half())
+    }
+}
+
+/// Functions/Methods for the dual form of the DHK128.
+impl DualHashKey128 {
+    /// Creates a new [DualHashKey128] from the pair of high and low sequences of bytes.
+    pub const fn from_dual_bytes(high: &[u8], low: &[u8]) -> Option<Self> {
+        Self::from_raw_dual(
+            hash::fnv1a_64(high, hash::FNV_OFFSET_BASIS_64),
+            hash::fnv1a_64(low, hash::FNV_OFFSET_BASIS_64)
+        )
+    }
+    
+    /// Creates a new [DualHashKey128] from the pair of high and low strings.
+    pub const fn from_dual_str(high: &str, low: &str) -> Option<Self> {
+        Self::from_raw_dual(
+            hash::fnv1a_64(high.as_bytes(), hash::FNV_OFFSET_BASIS_64),
+            hash::fnv1a_64(low.as_bytes(), hash::FNV_OFFSET_BASIS_64)
+        )
+    }
+    
+    /// Creates a new [DualHashKey128] from the high sequence of bytes, with the low-half zeroed.
+    pub const fn from_high_bytes(high: &[u8]) -> Option<Self> {
+        Self::from_raw_high(hash::fnv1a_64(high, hash::FNV_OFFSET_BASIS_64))
+    }
+    
+    /// Creates a new [DualHashKey128] from the high string, with the low-half zeroed.
+    pub const fn from_high_str(high: &str) -> Option<Self> {
+        Self::from_raw_high(hash::fnv1a_64(high.as_bytes(), hash::FNV_OFFSET_BASIS_64))
+    }
+}
+
+/// Functions/Methods for the raw form of the DHK128.
+impl DualHashKey128 {
+    /// Safely creates a new [DualHashKey128] from two raw [u64] values.
+    #[inline(always)]
+    pub const fn from_raw_dual(high: u64, low: u64) -> Option<Self> {
+        Self::from_raw((high as u128) << HIGH_SHIFT_128 | (low as u128))
+    }
+    
+    /// Safely creates a new [DualHashKey128] from a raw [u64] value for the high-half,
+    /// leaving the low-half zeroed out.
+    #[inline(always)]
+    pub const fn from_raw_high(high: u64) -> Option<Self> {
+        Self::from_raw((high as u128) << HIGH_SHIFT_128)
+    }
+    
+    /// Safely creates a new [DualHashKey128] from a raw [u128] value.
+    #[inline(always)]
+    pub const fn from_raw(hash: u128) -> Option<Self> {
+        match NonZeroU128::new(hash) {
+            Some(hash) => Some(Self {hash}),
+            None => None,
+        }
+    }
+    
+    /// Directly creates a new [DualHashKey128] from a raw [u128] value.
+    /// 
+    /// # Safety
+    /// This function is safe to call if-and-only-if the provided `hash` value is non-zero.
+    #[inline(always)]
+    pub const unsafe fn from_raw_unchecked(hash: u128) -> Self {
+        Self {hash: NonZeroU128::new_unchecked(hash)}
+    }
+    
+    /// Gets the wrapped hash value.
+    #[inline(always)]
+    pub const fn get_hash(&self) -> NonZeroU128 {
+        self.hash
+    }
+    
+    /// Gets the wrapped hash value as [u128].
+    /// 
+    /// This is the same as `dhk.get_hash().get()`.
+    #[inline(always)]
+    pub const fn get_hash_raw(&self) -> u128 {
+        self.hash.get()
+    }
+    
+    /// Gets the high-half of the hash.
+    #[inline(always)]
+    pub const fn get_hash_high_half(&self) -> u64 {
+        (self.get_hash_raw() >> HIGH_SHIFT_128) as u64
+    }
+    
+    /// Gets the low-half of the hash.
+    #[inline(always)]
+    pub const fn get_hash_low_half(&self) -> u64 {
+        (self.get_hash_raw() & LOW_MASK_128) as u64
+    }
+    
+    /// Checks if the low-half of the hash has any of its bits set.
+    #[inline(always)]
+    pub const fn is_hash_low_half_set(&self) -> bool {
+        self.get_hash_low_half() != 0
+    }
+    
+    /// Checks if the low-half of the hash has none of its bits set.
+    #[inline(always)]
+    pub const fn is_hash_low_half_clear(&self) -> bool {
+        self.get_hash_low_half() == 0
+    }
+    
+    /// Returns the hash with the low-half cleared.
+    #[inline(always)]
+    pub const fn get_hash_low_half_min(&self) -> Option<Self> {
+        Self::from_raw(self.get_hash_raw() & HIGH_MASK_128)
+    }
+    
+    /// Returns the hash with the low-half filled.
+    /// 
+    /// Since the low-half is filled with bits, making the DHK128 non-zero, this method cannot fail.
+    #[inline(always)]
+    pub const fn get_hash_low_half_max(&self) -> Self {
+        // # Safety
+        // The `| U64_MAX` operation *forces* the low-half bits to be set.
+        // As such, the raw DHK128 **cannot** be zero, so no check is needed.
+        unsafe {
+            Self::from_raw_unchecked(self.get_hash_raw() | LOW_MASK_128)
+        }
+    }
+}
+
+impl core::convert::From<NonZeroU128> for DualHashKey128 {
+    fn from(hash: NonZeroU128) -> Self {
+        Self { hash }
+    }
+}
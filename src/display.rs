@@ -0,0 +1,126 @@
+//! Alternative textual forms of a [DualHashKey].
+
+use crate::DualHashKey;
+
+/// Displays the raw value of a [DualHashKey] as 16 hex digits, without separator.
+#[derive(Clone, Copy)]
+struct Compact {
+    key: DualHashKey,
+    uppercase: bool
+}
+
+impl core::fmt::Display for Compact {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.uppercase {
+            write!(f, "{:0>16X}", self.key.get_hash_raw())
+        } else {
+            write!(f, "{:0>16x}", self.key.get_hash_raw())
+        }
+    }
+}
+
+/// Displays the raw value of a [DualHashKey] as `0x`-prefixed hex digits, without leading zeros.
+#[derive(Clone, Copy)]
+struct Short(DualHashKey);
+
+impl core::fmt::Display for Short {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:#X}", self.0.get_hash_raw())
+    }
+}
+
+/// Displays a [DualHashKey] as `HIGH<sep>LOW`, with a custom separator and casing.
+#[derive(Clone, Copy)]
+struct Custom {
+    key: DualHashKey,
+    sep: char,
+    uppercase: bool
+}
+
+impl core::fmt::Display for Custom {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let (high, low, sep) = (self.key.get_hash_high_half(), self.key.get_hash_low_half(), self.sep);
+        if self.uppercase {
+            write!(f, "{high:0>8X}{sep}{low:0>8X}")
+        } else {
+            write!(f, "{high:0>8x}{sep}{low:0>8x}")
+        }
+    }
+}
+
+/// Displays one half of a [DualHashKey] as 8 uppercase hex digits, like in the full form.
+#[derive(Clone, Copy)]
+struct HalfHex(u32);
+
+impl core::fmt::Display for HalfHex {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:0>8X}", self.0)
+    }
+}
+
+impl core::fmt::Debug for HalfHex {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(self, f)
+    }
+}
+
+/// Functions/Methods for the textual forms of the DHK.
+impl DualHashKey {
+    /// Returns a displayable form of this key like the [Display](core::fmt::Display) implementation,
+    /// but with a custom separator and casing; e.g. `e05f2e55:0cb0216d`.
+    pub fn display_with(&self, sep: char, uppercase: bool) -> impl core::fmt::Display {
+        Custom {key: *self, sep, uppercase}
+    }
+    
+    /// Returns a displayable form of just the high-half, as 8 uppercase hex digits.
+    pub fn high_half_hex(&self) -> impl core::fmt::Display + core::fmt::Debug {
+        HalfHex(self.get_hash_high_half())
+    }
+    
+    /// Returns a displayable form of just the low-half, as 8 uppercase hex digits.
+    pub fn low_half_hex(&self) -> impl core::fmt::Display + core::fmt::Debug {
+        HalfHex(self.get_hash_low_half())
+    }
+    
+    /// Returns a displayable form of this key as 16 uppercase hex digits, without separator.
+    pub fn compact(&self) -> impl core::fmt::Display {
+        Compact {key: *self, uppercase: true}
+    }
+    
+    /// Returns a displayable form of this key as 16 lowercase hex digits, without separator.
+    pub fn compact_lower(&self) -> impl core::fmt::Display {
+        Compact {key: *self, uppercase: false}
+    }
+    
+    /// Returns a displayable form of this key as `0x`-prefixed uppercase hex digits without leading zeros,
+    /// which saves width for small keys.
+    pub fn display_compact(&self) -> impl core::fmt::Display {
+        Short(*self)
+    }
+    
+    /// Returns the big-endian bytes of the raw value as a string,
+    /// if all 8 of them are printable ASCII characters.
+    /// 
+    /// Helpful when debugging keys that were constructed from short literal tags.
+    #[cfg(feature = "std")]
+    pub fn as_ascii_tag(&self) -> Option<String> {
+        let bytes = self.get_hash_raw().to_be_bytes();
+        if bytes.iter().all(|byte| (b' '..=b'~').contains(byte)) {
+            Some(bytes.iter().map(|byte| *byte as char).collect())
+        } else {
+            None
+        }
+    }
+    
+    /// Checks if the 16 hex digits of the [compact](Self::compact) form start with the given `prefix`,
+    /// ignoring case; for incremental search.
+    /// 
+    /// The `prefix` is compared digit by digit, so it may end in the middle of a byte.
+    pub fn matches_hex_prefix(&self, prefix: &str) -> bool {
+        let raw = self.get_hash_raw();
+        prefix.len() <= 16 && prefix.bytes().enumerate().all(|(i, digit)| {
+            let nibble = (raw >> (60 - 4 * i)) & 0xF;
+            char::from_digit(nibble as u32, 16).is_some_and(|hex| hex.eq_ignore_ascii_case(&(digit as char)))
+        })
+    }
+}
@@ -0,0 +1,80 @@
+//! A guard for editing the raw value of a [DualHashKey] in-place.
+
+use crate::{DualHashKey, NonZeroU64};
+
+/// A guard that derefs to the raw [u64] value of a [DualHashKey],
+/// writing it back into the key when dropped.
+/// 
+/// Created by [DualHashKey::edit_raw].
+/// 
+/// If the raw value is zero when the guard is dropped,
+/// this panics in debug builds; in release builds the edit is discarded,
+/// leaving the key as it was before.
+pub struct RawEdit<'a> {
+    key: &'a mut DualHashKey,
+    raw: u64
+}
+
+impl DualHashKey {
+    /// Returns a guard for editing the raw value of this key in-place.
+    /// 
+    /// See [RawEdit] for what happens if the raw value is zeroed.
+    pub fn edit_raw(&mut self) -> RawEdit<'_> {
+        let raw = self.get_hash_raw();
+        RawEdit {key: self, raw}
+    }
+}
+
+impl core::ops::Deref for RawEdit<'_> {
+    type Target = u64;
+    fn deref(&self) -> &Self::Target {
+        &self.raw
+    }
+}
+
+impl core::ops::DerefMut for RawEdit<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.raw
+    }
+}
+
+impl Drop for RawEdit<'_> {
+    fn drop(&mut self) {
+        debug_assert!(self.raw != 0, "raw value of DHK was set to zero");
+        if let Some(hash) = NonZeroU64::new(self.raw) {
+            self.key.hash = hash;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::DualHashKey;
+    
+    #[test]
+    fn valid_edit_is_written_back() {
+        let mut key = DualHashKey::from_raw_dual(1, 2).unwrap();
+        {
+            let mut raw = key.edit_raw();
+            *raw |= 0xF0;
+            *raw ^= 1 << 63;
+        }
+        assert_eq!(key.get_hash_raw(), (1 << 63) | (1 << 32) | 0xF2);
+    }
+    
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "raw value of DHK was set to zero")]
+    fn zeroing_panics_in_debug() {
+        let mut key = DualHashKey::from_raw_dual(1, 2).unwrap();
+        *key.edit_raw() = 0;
+    }
+    
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn zeroing_is_discarded_in_release() {
+        let mut key = DualHashKey::from_raw_dual(1, 2).unwrap();
+        *key.edit_raw() = 0;
+        assert_eq!(key.get_hash_raw(), (1 << 32) | 2);
+    }
+}
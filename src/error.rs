@@ -0,0 +1,38 @@
+//! The error type for fallible [DualHashKey](crate::DualHashKey) operations.
+
+/// Shorthand alias for [DualHashKeyError].
+pub type DhkError = DualHashKeyError;
+
+/// The reasons a [DualHashKey](crate::DualHashKey) could not be created.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DualHashKeyError {
+    /// The raw value of the key is zero.
+    ZeroRaw,
+    /// The string is missing the separator between the high-half and low-half.
+    MissingSeparator,
+    /// The high-half of the string is not a valid hex number.
+    InvalidHighHex,
+    /// The low-half of the string is not a valid hex number.
+    InvalidLowHex,
+    /// The generated hash of the high-half is zero.
+    ZeroHighHash,
+    /// The generated hash of the low-half is zero.
+    ZeroLowHash,
+}
+
+impl core::fmt::Display for DualHashKeyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::ZeroRaw => "raw value of DHK is zero",
+            Self::MissingSeparator => "missing separator between high-half and low-half of DHK",
+            Self::InvalidHighHex => "invalid hex number in high-half of DHK",
+            Self::InvalidLowHex => "invalid hex number in low-half of DHK",
+            Self::ZeroHighHash => "generated hash of high-half is zero",
+            Self::ZeroLowHash => "generated hash of low-half is zero",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DualHashKeyError {}
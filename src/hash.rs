@@ -0,0 +1,113 @@
+//! The hash functions used to compute the halves of a [DualHashKey](crate::DualHashKey).
+//! 
+//! These are implemented here (instead of relying on a dependency)
+//! so their output is pinned to this crate and cannot change underneath it.
+//! 
+//! Alternative hash functions are available behind the `xxhash` and `seahash` features,
+//! in the `xx` and `sea` modules; unlike FNV-1a, they cannot be used in `const` contexts.
+
+#[cfg(feature = "seahash")]
+pub mod sea;
+#[cfg(feature = "xxhash")]
+pub mod xx;
+
+/// The 32-bit FNV-1a offset basis; the default seed of the seeded constructors.
+pub const FNV_OFFSET_BASIS_32: u32 = 0x811c9dc5;
+
+/// The 32-bit FNV-1a prime.
+pub(crate) const FNV_PRIME_32: u32 = 0x01000193;
+
+/// Computes the 32-bit FNV-1a hash of the given bytes, starting from the given `basis`.
+pub(crate) const fn fnv1a_32(bytes: &[u8], basis: u32) -> u32 {
+    let mut hash = basis;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u32;
+        hash = hash.wrapping_mul(FNV_PRIME_32);
+        i += 1;
+    }
+    hash
+}
+
+/// Computes the 32-bit FNV-1a hash of the little-endian bytes of the given UTF-16 code units,
+/// starting from the given `basis`.
+pub(crate) const fn fnv1a_32_utf16_le(units: &[u16], basis: u32) -> u32 {
+    let mut hash = basis;
+    let mut i = 0;
+    while i < units.len() {
+        let [a, b] = units[i].to_le_bytes();
+        hash ^= a as u32;
+        hash = hash.wrapping_mul(FNV_PRIME_32);
+        hash ^= b as u32;
+        hash = hash.wrapping_mul(FNV_PRIME_32);
+        i += 1;
+    }
+    hash
+}
+
+/// Computes the 32-bit FNV-1a hash of the given bytes with every ASCII letter lower-cased,
+/// starting from the given `basis`.
+pub(crate) const fn fnv1a_32_ascii_ci(bytes: &[u8], basis: u32) -> u32 {
+    let mut hash = basis;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i].to_ascii_lowercase() as u32;
+        hash = hash.wrapping_mul(FNV_PRIME_32);
+        i += 1;
+    }
+    hash
+}
+
+/// The 64-bit FNV-1a offset basis.
+pub const FNV_OFFSET_BASIS_64: u64 = 0xcbf29ce484222325;
+
+/// The 64-bit FNV-1a prime.
+pub(crate) const FNV_PRIME_64: u64 = 0x00000100000001b3;
+
+/// Computes the 64-bit FNV-1a hash of the given bytes, starting from the given `basis`.
+pub(crate) const fn fnv1a_64(bytes: &[u8], basis: u64) -> u64 {
+    let mut hash = basis;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(FNV_PRIME_64);
+        i += 1;
+    }
+    hash
+}
+
+/// A 32-bit hash function, for computing the halves of a [DualHashKey](crate::DualHashKey).
+/// 
+/// Implemented for all `Fn(&[u8]) -> u32` closures and functions.
+pub trait Hasher32 {
+    /// Computes the 32-bit hash of the given bytes.
+    fn hash32(&self, bytes: &[u8]) -> u32;
+}
+
+impl<F: Fn(&[u8]) -> u32> Hasher32 for F {
+    fn hash32(&self, bytes: &[u8]) -> u32 {
+        self(bytes)
+    }
+}
+
+/// The 32-bit FNV-1a hash function, as used by the non-generic constructors.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Fnv1a32;
+
+impl Hasher32 for Fnv1a32 {
+    fn hash32(&self, bytes: &[u8]) -> u32 {
+        fnv1a_32(bytes, FNV_OFFSET_BASIS_32)
+    }
+}
+
+/// The 32-bit finalizer (`fmix32`) of MurmurHash3, improving the avalanche of a hash.
+/// 
+/// This is a bijection that maps zero to zero, and only zero to zero.
+pub const fn fmix32(mut h: u32) -> u32 {
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85eb_ca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2_ae35);
+    h ^= h >> 16;
+    h
+}
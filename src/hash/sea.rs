@@ -0,0 +1,27 @@
+//! Constructors computing the halves of a [DualHashKey] with SeaHash.
+//! 
+//! SeaHash produces 64-bit hashes, which are folded into 32 bits by XOR-ing their upper and lower halves.
+
+use crate::hash::Hasher32;
+use crate::DualHashKey;
+
+/// The SeaHash hash function, XOR-folded into 32 bits.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SeaHash32;
+
+impl Hasher32 for SeaHash32 {
+    fn hash32(&self, bytes: &[u8]) -> u32 {
+        let hash = seahash::hash(bytes);
+        (hash >> 32) as u32 ^ hash as u32
+    }
+}
+
+/// Creates a new [DualHashKey] from the pair of high and low sequences of bytes.
+pub fn from_dual_bytes(high: &[u8], low: &[u8]) -> Option<DualHashKey> {
+    DualHashKey::from_dual_with(high, low, SeaHash32)
+}
+
+/// Creates a new [DualHashKey] from the pair of high and low strings.
+pub fn from_dual_str(high: &str, low: &str) -> Option<DualHashKey> {
+    from_dual_bytes(high.as_bytes(), low.as_bytes())
+}
@@ -0,0 +1,24 @@
+//! Constructors computing the halves of a [DualHashKey] with 32-bit xxHash (XXH32), using a seed of zero.
+
+use crate::hash::Hasher32;
+use crate::DualHashKey;
+
+/// The 32-bit xxHash (XXH32) hash function, with a seed of zero.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Xxh32;
+
+impl Hasher32 for Xxh32 {
+    fn hash32(&self, bytes: &[u8]) -> u32 {
+        xxhash_rust::xxh32::xxh32(bytes, 0)
+    }
+}
+
+/// Creates a new [DualHashKey] from the pair of high and low sequences of bytes.
+pub fn from_dual_bytes(high: &[u8], low: &[u8]) -> Option<DualHashKey> {
+    DualHashKey::from_dual_with(high, low, Xxh32)
+}
+
+/// Creates a new [DualHashKey] from the pair of high and low strings.
+pub fn from_dual_str(high: &str, low: &str) -> Option<DualHashKey> {
+    from_dual_bytes(high.as_bytes(), low.as_bytes())
+}
@@ -0,0 +1,66 @@
+//! A pass-through [Hasher] for [DualHashKey]s, since they are hashes already.
+
+use core::hash::{BuildHasher, Hasher};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+use crate::{hash, DualHashKey};
+
+/// A [HashMap] keyed by [DualHashKey]s, using the pass-through [BuildDualHashKeyHasher].
+#[cfg(feature = "std")]
+pub type DhkHashMap<V> = HashMap<DualHashKey, V, BuildDualHashKeyHasher>;
+
+/// A [HashSet] of [DualHashKey]s, using the pass-through [BuildDualHashKeyHasher].
+#[cfg(feature = "std")]
+pub type DhkHashSet = HashSet<DualHashKey, BuildDualHashKeyHasher>;
+
+/// A [Hasher] that stores the [u64] written via `write_u64` as-is, and returns it from `finish`.
+/// 
+/// This is meant to be used *only* with [DualHashKey]s, which write their raw value via `write_u64`.
+/// Writing anything else is a bug that trips a debug assertion;
+/// in release builds, such bytes are mixed in via FNV-1a instead.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DualHashKeyHasher {
+    hash: u64
+}
+
+impl Hasher for DualHashKeyHasher {
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+    
+    fn write(&mut self, bytes: &[u8]) {
+        debug_assert!(false, "DualHashKeyHasher must only be used to hash a DualHashKey");
+        self.hash = hash::fnv1a_64(bytes, self.hash);
+    }
+    
+    fn write_u64(&mut self, i: u64) {
+        self.hash = i;
+    }
+}
+
+/// A [BuildHasher] creating [DualHashKeyHasher]s.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BuildDualHashKeyHasher;
+
+impl BuildHasher for BuildDualHashKeyHasher {
+    type Hasher = DualHashKeyHasher;
+    fn build_hasher(&self) -> Self::Hasher {
+        DualHashKeyHasher::default()
+    }
+}
+
+/// Functions for hashing DHKs with generic hashers.
+impl DualHashKey {
+    /// Feeds the high-half and then the low-half into the `state`, as two separate `write_u32` calls.
+    /// 
+    /// The [Hash](core::hash::Hash) impl writes the raw value via a single `write_u64` instead,
+    /// which is what the pass-through [DualHashKeyHasher] expects; this is for hashers that
+    /// consume their input in [u32] chunks, and would otherwise disagree with a `[u32; 2]` layout.
+    /// 
+    /// Do *not* use this with the [DualHashKeyHasher]: it trips its debug assertion.
+    pub fn hash_halves<H: Hasher>(&self, state: &mut H) {
+        state.write_u32(self.get_hash_high_half());
+        state.write_u32(self.get_hash_low_half());
+    }
+}
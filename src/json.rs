@@ -0,0 +1,70 @@
+//! Reading [DualHashKey]s from untyped [serde_json::Value]s.
+
+use crate::DualHashKey;
+
+/// Parses a string of hex digits (and nothing else) as a raw value.
+fn parse_hex(hex: &str) -> Option<u64> {
+    if !hex.is_empty() && hex.bytes().all(|digit| digit.is_ascii_hexdigit()) {
+        u64::from_str_radix(hex, 16).ok()
+    } else {
+        None
+    }
+}
+
+impl DualHashKey {
+    /// Reads a key from an untyped JSON value: either a string holding the raw value in hex,
+    /// or a number holding the raw value.
+    /// 
+    /// The string may be in the `HIGH.LOW` form, the undotted [compact](Self::compact) form
+    /// of exactly 16 hex digits, or `0x`-prefixed hex; there is no decimal string form.
+    /// 
+    /// Returns `None` for any other kind of value, or if the key would be zero.
+    pub fn from_json(value: &serde_json::Value) -> Option<Self> {
+        match value {
+            serde_json::Value::String(s) => {
+                if let Ok(key) = s.parse() {
+                    return Some(key);
+                }
+                let raw = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+                    Some(hex) => parse_hex(hex)?,
+                    None if s.len() == 16 => parse_hex(s)?,
+                    None => return None,
+                };
+                Self::from_raw(raw)
+            },
+            serde_json::Value::Number(n) => Self::from_raw(n.as_u64()?),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    
+    use crate::DualHashKey;
+    
+    #[test]
+    fn parses_all_string_forms() {
+        let key = DualHashKey::from_raw(0x00000001_0000000A).unwrap();
+        assert_eq!(DualHashKey::from_json(&json!(key.to_string())), Some(key));
+        assert_eq!(DualHashKey::from_json(&json!(key.compact().to_string())), Some(key));
+        assert_eq!(DualHashKey::from_json(&json!("0x10000000A")), Some(key));
+        assert_eq!(DualHashKey::from_json(&json!(0x00000001_0000000A_u64)), Some(key));
+    }
+    
+    #[test]
+    fn parses_prefixed_hex_of_compact_length() {
+        let key = DualHashKey::from_raw(0x1234567890ABCD).unwrap();
+        assert_eq!(DualHashKey::from_json(&json!("0x1234567890ABCD")), Some(key));
+        assert_eq!(DualHashKey::from_json(&json!("0X001234567890ABCD")), Some(key));
+    }
+    
+    #[test]
+    fn rejects_decimal_and_zero() {
+        assert_eq!(DualHashKey::from_json(&json!("12345")), None);
+        assert_eq!(DualHashKey::from_json(&json!("0000000000000000")), None);
+        assert_eq!(DualHashKey::from_json(&json!("0x")), None);
+        assert_eq!(DualHashKey::from_json(&json!(0)), None);
+    }
+}
@@ -2,9 +2,66 @@
 //! 
 //! For use with hierarchical ordered collections,
 //! to quickly find subkeys and permit range queries.
+//! 
+//! Without the default `std` feature, the crate is `no_std` (and needs no `alloc`);
+//! functions returning collections and the collection wrappers are then unavailable.
+
+#![cfg_attr(not(feature = "std"), no_std)]
 
 pub use core::num::NonZeroU64;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "borsh")]
+mod borsh;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+#[cfg(feature = "cache")]
+mod cache;
+#[cfg(feature = "std")]
+pub mod collections;
+mod dhk128;
+mod display;
+mod edit;
+mod error;
+pub mod hash;
+mod hasher;
+#[cfg(feature = "serde_json")]
+mod json;
+#[cfg(feature = "std")]
+mod map;
+mod morton;
+mod packed;
+mod parse;
+#[cfg(feature = "std")]
+mod path;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+mod range;
+mod raw;
+mod resolve;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "std")]
+mod set;
+mod slice;
+
+#[cfg(feature = "cache")]
+pub use cache::CACHE_CAPACITY;
+pub use dhk128::{DualHashKey128, DHK128, HIGH_MASK_128, HIGH_SHIFT_128, LOW_MASK_128, MAX_128, MIN_128, NonZeroU128};
+pub use edit::RawEdit;
+pub use error::{DhkError, DualHashKeyError};
+pub use hash::Hasher32;
+pub use hasher::{BuildDualHashKeyHasher, DualHashKeyHasher};
+#[cfg(feature = "std")]
+pub use hasher::{DhkHashMap, DhkHashSet};
+pub use packed::PackedDhk;
+pub use range::SubtreeBound;
+pub use raw::RawDualHashKey;
+pub use resolve::PathResolver;
+#[cfg(feature = "std")]
+pub use set::DhkSet;
+
 /// Shorthand alias for [DualHashKey].
 pub type DHK = DualHashKey;
 
@@ -25,8 +82,30 @@ pub const MIN: DualHashKey = DualHashKey {hash: NonZeroU64::MIN};
 /// The highest possible [DualHashKey].
 pub const MAX: DualHashKey = DualHashKey {hash: NonZeroU64::MAX};
 
+/// The separator between the high-half and low-half in the textual form of a [DualHashKey].
+pub const SEPARATOR: char = '.';
+
+/// One of the two halves of a [DualHashKey].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Half {
+    /// The high-half, identifying the parent.
+    High,
+    /// The low-half, identifying the child.
+    Low,
+}
+
+impl Half {
+    /// Both halves, from high to low.
+    pub const ALL: [Half; 2] = [Half::High, Half::Low];
+}
+
 /// A key made of two hashes, whose raw value is never zero.
+/// 
+/// With the `rkyv` feature, the archived form is the raw [u64] (as little-endian [NonZeroU64]),
+/// and validating an archive rejects zero keys.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature = "rkyv", rkyv(compare(PartialEq, PartialOrd), derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)))]
 #[repr(transparent)]
 pub struct DualHashKey {
     pub hash: NonZeroU64
@@ -34,9 +113,11 @@ pub struct DualHashKey {
 
 /// Hash-implementation: Writes the hash via `write_u64`. That's it.
 /// 
-/// One should use a passthru/nohash-hasher when using the [DualHashKey].
+/// One should use a passthru/nohash-hasher when using the [DualHashKey],
+/// such as the [BuildDualHashKeyHasher] (see `DhkHashMap` and `DhkHashSet`).
+/// For hashers that expect the halves separately, see [DualHashKey::hash_halves].
 impl core::hash::Hash for DualHashKey {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         state.write_u64(self.get_hash_raw())
     }
 }
@@ -51,55 +132,413 @@ impl core::fmt::Debug for DualHashKey {
     }
 }
 
+/// Displays the key as `HIGH.LOW`, with both halves as 8 uppercase hex digits.
+impl core::fmt::Display for DualHashKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:0>8X}{SEPARATOR}{:0>8X}", self.get_hash_high_half(), self.get_hash_low_half())
+    }
+}
+
+/// Compares the raw value of the key against a raw [u64].
+impl PartialEq<u64> for DualHashKey {
+    fn eq(&self, other: &u64) -> bool {
+        self.get_hash_raw() == *other
+    }
+}
+
+/// Compares a raw [u64] against the raw value of the key.
+impl PartialEq<DualHashKey> for u64 {
+    fn eq(&self, other: &DualHashKey) -> bool {
+        *self == other.get_hash_raw()
+    }
+}
+
+/// Compares the raw value of the key against a [NonZeroU64].
+impl PartialEq<NonZeroU64> for DualHashKey {
+    fn eq(&self, other: &NonZeroU64) -> bool {
+        self.hash == *other
+    }
+}
+
+/// Compares a [NonZeroU64] against the raw value of the key.
+impl PartialEq<DualHashKey> for NonZeroU64 {
+    fn eq(&self, other: &DualHashKey) -> bool {
+        *self == other.hash
+    }
+}
+
+/// Orders the key against a raw [u64], in raw order.
+impl PartialOrd<u64> for DualHashKey {
+    fn partial_cmp(&self, other: &u64) -> Option<core::cmp::Ordering> {
+        self.get_hash_raw().partial_cmp(other)
+    }
+}
+
+/// Orders a raw [u64] against the key, in raw order.
+impl PartialOrd<DualHashKey> for u64 {
+    fn partial_cmp(&self, other: &DualHashKey) -> Option<core::cmp::Ordering> {
+        self.partial_cmp(&other.get_hash_raw())
+    }
+}
+
 /// Functions/Methods for the dual form of the DHK.
 impl DualHashKey {
     /// Creates a new [DualHashKey] from the pair of high and low sequences of bytes.
     pub const fn from_dual_bytes(high: &[u8], low: &[u8]) -> Option<Self> {
-        Self::from_raw_dual(
-            const_fnv1a_hash::fnv1a_hash_32(high, None),
-            const_fnv1a_hash::fnv1a_hash_32(low, None)
-        )
+        Self::from_dual_bytes_seeded(high, low, hash::FNV_OFFSET_BASIS_32)
     }
     
     /// Creates a new [DualHashKey] from the pair of high and low strings.
     pub const fn from_dual_str(high: &str, low: &str) -> Option<Self> {
+        Self::from_dual_str_seeded(high, low, hash::FNV_OFFSET_BASIS_32)
+    }
+    
+    /// Creates a new [DualHashKey] from the pair of high and low sequences of bytes,
+    /// hashing both with the `seed` as FNV-1a offset basis.
+    /// 
+    /// Different seeds give different keys for the same bytes, keeping independent namespaces apart;
+    /// the default seed is [FNV_OFFSET_BASIS_32](hash::FNV_OFFSET_BASIS_32).
+    pub const fn from_dual_bytes_seeded(high: &[u8], low: &[u8], seed: u32) -> Option<Self> {
         Self::from_raw_dual(
-            const_fnv1a_hash::fnv1a_hash_str_32(high),
-            const_fnv1a_hash::fnv1a_hash_str_32(low)
+            hash::fnv1a_32(high, seed),
+            hash::fnv1a_32(low, seed)
         )
     }
     
+    /// Creates a new [DualHashKey] from the pair of high and low strings,
+    /// hashing both with the `seed` as FNV-1a offset basis.
+    /// 
+    /// See [Self::from_dual_bytes_seeded].
+    pub const fn from_dual_str_seeded(high: &str, low: &str, seed: u32) -> Option<Self> {
+        Self::from_dual_bytes_seeded(high.as_bytes(), low.as_bytes(), seed)
+    }
+    
+    /// Creates a new [DualHashKey] from a `prefix` and the `suffix` following it,
+    /// with the hash of the `prefix` as the high-half and the hash of the whole sequence as the low-half.
+    /// 
+    /// The FNV-1a state after the `prefix` is continued over the `suffix`, so the shared prefix is hashed only once;
+    /// the result is the same as `from_dual_bytes(prefix, prefix ++ suffix)`.
+    pub const fn from_prefixed_bytes(prefix: &[u8], suffix: &[u8]) -> Option<Self> {
+        let high = hash::fnv1a_32(prefix, hash::FNV_OFFSET_BASIS_32);
+        Self::from_raw_dual(high, hash::fnv1a_32(suffix, high))
+    }
+    
+    /// Creates a new [DualHashKey] from the pair of high and low strings,
+    /// or from just the high string (with the low-half zeroed) if `low` is empty.
+    /// 
+    /// This distinguishes a reference to the parent itself (e.g. a directory) from its children (e.g. files).
+    pub const fn from_dual_str_flexible(high: &str, low: &str) -> Option<Self> {
+        if low.is_empty() {
+            Self::from_high_str(high)
+        } else {
+            Self::from_dual_str(high, low)
+        }
+    }
+    
+    /// Creates a new [DualHashKey] from the pair of high and low UTF-16 strings.
+    /// 
+    /// Each code unit is hashed as its two little-endian bytes, regardless of the target's endianness,
+    /// so this is the same as calling [Self::from_dual_bytes] with the UTF-16LE encoding of both strings.
+    pub const fn from_dual_utf16(high: &[u16], low: &[u16]) -> Option<Self> {
+        Self::from_raw_dual(
+            hash::fnv1a_32_utf16_le(high, hash::FNV_OFFSET_BASIS_32),
+            hash::fnv1a_32_utf16_le(low, hash::FNV_OFFSET_BASIS_32)
+        )
+    }
+    
+    /// Creates a new [DualHashKey] from the pair of high and low sequences of bytes,
+    /// ignoring the case of ASCII letters.
+    /// 
+    /// Only the ASCII letters `A-Z` are folded (to `a-z`); all other bytes, including
+    /// non-ASCII UTF-8 sequences, are hashed as-is. This is *not* full Unicode case-folding.
+    pub const fn from_dual_bytes_ascii_ci(high: &[u8], low: &[u8]) -> Option<Self> {
+        Self::from_raw_dual(
+            hash::fnv1a_32_ascii_ci(high, hash::FNV_OFFSET_BASIS_32),
+            hash::fnv1a_32_ascii_ci(low, hash::FNV_OFFSET_BASIS_32)
+        )
+    }
+    
+    /// Creates a new [DualHashKey] from the pair of high and low strings,
+    /// ignoring the case of ASCII letters, so that `Root`/`File` and `root`/`file` are the same key.
+    /// 
+    /// Only ASCII is folded, not full Unicode case: `Ä` and `ä` still produce different keys.
+    /// See [Self::from_dual_bytes_ascii_ci].
+    pub const fn from_dual_str_ascii_ci(high: &str, low: &str) -> Option<Self> {
+        Self::from_dual_bytes_ascii_ci(high.as_bytes(), low.as_bytes())
+    }
+    
+    /// Creates a new [DualHashKey] from the pair of high and low strings,
+    /// reporting which of the halves hashed to zero.
+    /// 
+    /// Unlike [Self::from_dual_str], this rejects a zero hash in *either* half:
+    /// [DhkError::ZeroHighHash] or [DhkError::ZeroLowHash] if only one of them is zero,
+    /// [DhkError::ZeroRaw] if both are.
+    pub const fn from_dual_str_diagnosed(high: &str, low: &str) -> Result<Self, DhkError> {
+        let high = hash::fnv1a_32(high.as_bytes(), hash::FNV_OFFSET_BASIS_32);
+        let low = hash::fnv1a_32(low.as_bytes(), hash::FNV_OFFSET_BASIS_32);
+        match (high, low) {
+            (0, 0) => Err(DhkError::ZeroRaw),
+            (0, _) => Err(DhkError::ZeroHighHash),
+            (_, 0) => Err(DhkError::ZeroLowHash),
+            // # Safety
+            // Both halves are non-zero, so the raw DHK cannot be zero.
+            _ => Ok(unsafe { Self::from_raw_unchecked((high as u64) << HIGH_SHIFT | (low as u64)) }),
+        }
+    }
+    
+    /// Creates a new [DualHashKey] from the pair of high and low strings,
+    /// for use in `const` contexts that need a plain value.
+    /// 
+    /// # Panics
+    /// Panics (at compile-time, when used in a `const`) if the generated key is zero.
+    pub const fn from_dual_str_const(high: &str, low: &str) -> Self {
+        match Self::from_dual_str(high, low) {
+            Some(key) => key,
+            None => panic!("generated hash of high-half and low-half strings is zero"),
+        }
+    }
+    
+    /// Creates a new [DualHashKey] from the pair of high and low strings, panicking if it is zero;
+    /// same as [Self::from_dual_str_const], for building static key tables.
+    /// 
+    /// # Panics
+    /// Panics (at compile-time, when used in a `const`) if the generated key is zero.
+    #[inline(always)]
+    pub const fn from_dual_str_or_panic(high: &str, low: &str) -> Self {
+        Self::from_dual_str_const(high, low)
+    }
+    
     /// Creates a new [DualHashKey] from the high sequence of bytes, with the low-half zeroed.
     pub const fn from_high_bytes(high: &[u8]) -> Option<Self> {
-        Self::from_raw_high(const_fnv1a_hash::fnv1a_hash_32(high, None))
+        Self::from_raw_high(hash::fnv1a_32(high, hash::FNV_OFFSET_BASIS_32))
     }
     
     /// Creates a new [DualHashKey] from the high string, with the low-half zeroed.
     pub const fn from_high_str(high: &str) -> Option<Self> {
-        Self::from_raw_high(const_fnv1a_hash::fnv1a_hash_str_32(high))
+        Self::from_raw_high(hash::fnv1a_32(high.as_bytes(), hash::FNV_OFFSET_BASIS_32))
+    }
+    
+    /// Creates a new [DualHashKey] from the bytes of the C string (excluding the nul terminator), with the low-half zeroed.
+    pub const fn from_cstr(cstr: &core::ffi::CStr) -> Option<Self> {
+        Self::from_high_bytes(cstr.to_bytes())
     }
     
     /// Creates a copy with the high-half replaced.
     pub const fn with_high_half_bytes(&self, high: &[u8]) -> Option<Self> {
-        self.with_high_half_raw(const_fnv1a_hash::fnv1a_hash_32(high, None))
+        self.with_high_half_raw(hash::fnv1a_32(high, hash::FNV_OFFSET_BASIS_32))
     }
     
     /// Creates a copy with the high-half replaced.
     pub const fn with_high_half_str(&self, high: &str) -> Option<Self> {
-        self.with_high_half_raw(const_fnv1a_hash::fnv1a_hash_str_32(high))
+        self.with_high_half_raw(hash::fnv1a_32(high.as_bytes(), hash::FNV_OFFSET_BASIS_32))
     }
     
     /// Creates a copy with the low-half replaced.
     pub const fn with_low_half_bytes(&self, low: &[u8]) -> Option<Self> {
-        self.with_low_half_raw(const_fnv1a_hash::fnv1a_hash_32(low, None))
+        self.with_low_half_raw(hash::fnv1a_32(low, hash::FNV_OFFSET_BASIS_32))
     }
     
     /// Creates a copy with the low-half replaced.
     pub const fn with_low_half_str(&self, low: &str) -> Option<Self> {
-        self.with_low_half_raw(const_fnv1a_hash::fnv1a_hash_str_32(low))
+        self.with_low_half_raw(hash::fnv1a_32(low.as_bytes(), hash::FNV_OFFSET_BASIS_32))
+    }
+    
+    /// Creates a copy with the low-half replaced by the XOR of the hashes of all `sources`.
+    /// 
+    /// Since XOR is commutative, the order of the sources does not matter;
+    /// on the flip side, a source that appears twice cancels itself out,
+    /// and empty `sources` clear the low-half.
+    pub const fn low_half_from_many(&self, sources: &[&[u8]]) -> Option<Self> {
+        let mut low = 0;
+        let mut i = 0;
+        while i < sources.len() {
+            low ^= hash::fnv1a_32(sources[i], hash::FNV_OFFSET_BASIS_32);
+            i += 1;
+        }
+        self.with_low_half_raw(low)
+    }
+    
+    /// Creates a copy with the low-half replaced, hashing `low` with the `seed` as FNV-1a offset basis.
+    /// 
+    /// Different seeds give different low-halves for the same bytes,
+    /// namespacing the children under a fixed parent.
+    pub const fn with_low_half_bytes_seeded(&self, low: &[u8], seed: u32) -> Option<Self> {
+        self.with_low_half_raw(hash::fnv1a_32(low, seed))
+    }
+    
+    /// Creates a new [DualHashKey] from the segments of a path, without joining them first:
+    /// the high-half is the hash of all segments but the last, the low-half the hash of all segments,
+    /// with the segments separated by a `/` in both.
+    /// 
+    /// As such, `[root, mid, low, name]` gives the same key as `from_dual_bytes(b"root/mid/low", b"root/mid/low/name")`;
+    /// each segment is hashed only once, continuing the hash across segments.
+    /// Returns `None` if there are no segments, or if the generated key is zero.
+    pub fn from_segments<'a>(segments: impl IntoIterator<Item = &'a [u8]>) -> Option<Self> {
+        let mut high = hash::FNV_OFFSET_BASIS_32;
+        let mut full = None;
+        for segment in segments {
+            let basis = match full {
+                Some(prefix) => {
+                    high = prefix;
+                    hash::fnv1a_32(b"/", prefix)
+                },
+                None => hash::FNV_OFFSET_BASIS_32,
+            };
+            full = Some(hash::fnv1a_32(segment, basis));
+        }
+        Self::from_raw_dual(high, full?)
+    }
+    
+    /// Creates a [DualHashKey] for each of the low sequences of bytes, sharing the high sequence of bytes.
+    /// 
+    /// The high-half is hashed only once, instead of once per key.
+    #[cfg(feature = "std")]
+    pub fn children_of_bytes<'a>(high: &[u8], lows: impl IntoIterator<Item = &'a [u8]>) -> Vec<Option<Self>> {
+        let high = hash::fnv1a_32(high, hash::FNV_OFFSET_BASIS_32);
+        lows.into_iter()
+            .map(|low| Self::from_raw_dual(high, hash::fnv1a_32(low, hash::FNV_OFFSET_BASIS_32)))
+            .collect()
+    }
+    
+    /// Creates a [DualHashKey] for each of the child `names`, sharing the `high` string,
+    /// lazily and without allocating.
+    /// 
+    /// The high-half is hashed only once, so only the names are hashed per key.
+    pub fn children_of<'a, I: IntoIterator<Item = &'a str>>(high: &str, names: I) -> impl Iterator<Item = Option<Self>> + use<'a, I> {
+        let high = hash::fnv1a_32(high.as_bytes(), hash::FNV_OFFSET_BASIS_32);
+        names.into_iter()
+            .map(move |name| Self::from_raw_dual(high, hash::fnv1a_32(name.as_bytes(), hash::FNV_OFFSET_BASIS_32)))
+    }
+}
+
+/// Functions/Methods for the mixed form of the DHK.
+/// 
+/// Mixing runs each half through the [fmix32](hash::fmix32) finalizer,
+/// spreading out the hashes of short and similar inputs that FNV-1a tends to cluster.
+/// Mixing must be applied consistently: a mixed key is only comparable to other mixed keys.
+/// Since the finalizer maps zero to zero (and only zero), which halves are zero is preserved.
+impl DualHashKey {
+    /// Creates a new [DualHashKey] from the pair of high and low sequences of bytes, with both halves mixed.
+    pub const fn from_dual_bytes_mixed(high: &[u8], low: &[u8]) -> Option<Self> {
+        Self::from_raw_dual(
+            hash::fmix32(hash::fnv1a_32(high, hash::FNV_OFFSET_BASIS_32)),
+            hash::fmix32(hash::fnv1a_32(low, hash::FNV_OFFSET_BASIS_32))
+        )
+    }
+    
+    /// Creates a new [DualHashKey] from the pair of high and low strings, with both halves mixed.
+    pub const fn from_dual_str_mixed(high: &str, low: &str) -> Option<Self> {
+        Self::from_dual_bytes_mixed(high.as_bytes(), low.as_bytes())
+    }
+    
+    /// Returns a copy with both halves mixed.
+    /// 
+    /// Since a non-zero key stays non-zero, this cannot fail.
+    #[inline(always)]
+    pub const fn mixed(&self) -> Self {
+        let high = hash::fmix32(self.get_hash_high_half());
+        let low = hash::fmix32(self.get_hash_low_half());
+        // # Safety
+        // The finalizer maps non-zero halves to non-zero halves,
+        // so the raw DHK **cannot** become zero.
+        unsafe {
+            Self::from_raw_unchecked((high as u64) << HIGH_SHIFT | (low as u64))
+        }
+    }
+}
+
+/// Functions/Methods for the generic form of the DHK.
+impl DualHashKey {
+    /// Creates a new [DualHashKey] from the pair of high and low sequences of bytes,
+    /// using the given 32-bit `hasher` instead of FNV-1a.
+    pub fn from_dual_with(high: &[u8], low: &[u8], hasher: impl Hasher32) -> Option<Self> {
+        Self::from_raw_dual(hasher.hash32(high), hasher.hash32(low))
+    }
+    
+    /// Creates a new [DualHashKey] from the high sequence of bytes, with the low-half zeroed,
+    /// using the given 32-bit `hasher` instead of FNV-1a.
+    pub fn from_high_with(high: &[u8], hasher: impl Hasher32) -> Option<Self> {
+        Self::from_raw_high(hasher.hash32(high))
+    }
+    
+    /// Creates a copy with the high-half replaced,
+    /// using the given 32-bit `hasher` instead of FNV-1a.
+    pub fn with_high_half_with(&self, high: &[u8], hasher: impl Hasher32) -> Option<Self> {
+        self.with_high_half_raw(hasher.hash32(high))
+    }
+    
+    /// Creates a copy with the low-half replaced,
+    /// using the given 32-bit `hasher` instead of FNV-1a.
+    pub fn with_low_half_with(&self, low: &[u8], hasher: impl Hasher32) -> Option<Self> {
+        self.with_low_half_raw(hasher.hash32(low))
+    }
+}
+
+/// Functions for the hashes used by the DHK.
+impl DualHashKey {
+    /// Computes the 32-bit FNV-1a hash of the bytes, as used for the halves of a [DualHashKey].
+    #[inline(always)]
+    pub const fn fnv32(bytes: &[u8]) -> u32 {
+        hash::fnv1a_32(bytes, hash::FNV_OFFSET_BASIS_32)
+    }
+    
+    /// Computes the 32-bit FNV-1a hash of the bytes with the `seed` as offset basis,
+    /// as used by the seeded constructors.
+    #[inline(always)]
+    pub const fn fnv32_seeded(bytes: &[u8], seed: u32) -> u32 {
+        hash::fnv1a_32(bytes, seed)
+    }
+    
+    /// Computes the 64-bit FNV-1a hash of the bytes.
+    #[inline(always)]
+    pub const fn fnv64(bytes: &[u8]) -> u64 {
+        hash::fnv1a_64(bytes, hash::FNV_OFFSET_BASIS_64)
+    }
+}
+
+/// Functions/Methods for the full-width form of the DHK.
+impl DualHashKey {
+    /// Creates a new [DualHashKey] from a single 64-bit FNV-1a hash of the string,
+    /// using the `seed` as offset basis.
+    /// 
+    /// The hash covers the full width of the key, so the halves carry no meaning:
+    /// such keys are not hierarchical and shouldn't be used with the subtree functions.
+    pub const fn from_str_seeded_64(s: &str, seed: u64) -> Option<Self> {
+        Self::from_raw(hash::fnv1a_64(s.as_bytes(), seed))
+    }
+}
+
+/// Functions/Methods for the stable form of the DHK.
+impl DualHashKey {
+    /// Creates a new [DualHashKey] from the pair of high and low strings,
+    /// using a version-pinned 32-bit FNV-1a implementation.
+    /// 
+    /// The output of this function is part of the crate's stable format:
+    /// it will produce the same key for the same input across *all* versions,
+    /// making it suitable for keys that are persisted to disk.
+    /// 
+    /// For example, `("root/mid/low", "root/mid/low/name")` is always `E05F2E55.0CB0216D`.
+    pub const fn stable_from_dual_str(high: &str, low: &str) -> Option<Self> {
+        Self::from_raw_dual(
+            hash::fnv1a_32(high.as_bytes(), hash::FNV_OFFSET_BASIS_32),
+            hash::fnv1a_32(low.as_bytes(), hash::FNV_OFFSET_BASIS_32)
+        )
     }
 }
 
+// Golden vectors locking the stable format; changing any of these is a breaking change.
+const _: () = {
+    const fn golden(high: &str, low: &str, raw: u64) -> bool {
+        match DualHashKey::stable_from_dual_str(high, low) {
+            Some(key) => key.get_hash_raw() == raw,
+            None => false,
+        }
+    }
+    assert!(golden("root/mid/low", "root/mid/low/name", 0xE05F2E55_0CB0216D));
+    assert!(golden("root", "root/mid", 0x20FD0E45_0E6BC1CC));
+    assert!(golden("foobar", "", 0xBF9CF968_811C9DC5));
+};
+
 /// Functions/Methods for the raw form of the DHK.
 impl DualHashKey {
     /// Safely creates a new [DualHashKey] from two raw [u32] values.
@@ -108,6 +547,19 @@ impl DualHashKey {
         Self::from_raw((high as u64) << HIGH_SHIFT | (low as u64))
     }
     
+    /// Safely creates a new [DualHashKey] from two precomputed [u32] hashes,
+    /// rejecting a zero high-half so that the key is always [walkable](Self::is_walkable).
+    /// 
+    /// Returns [DhkError::ZeroRaw] if both halves are zero, [DhkError::ZeroHighHash] if only the high-half is.
+    #[inline(always)]
+    pub const fn from_precomputed_walkable(high: u32, low: u32) -> Result<Self, DhkError> {
+        match (high, low) {
+            (0, 0) => Err(DhkError::ZeroRaw),
+            (0, _) => Err(DhkError::ZeroHighHash),
+            _ => Self::try_new((high as u64) << HIGH_SHIFT | (low as u64)),
+        }
+    }
+    
     /// Safely creates a new [DualHashKey] from a raw [u32] value for the high-half,
     /// leaving the low-half zeroed out.
     #[inline(always)]
@@ -115,6 +567,27 @@ impl DualHashKey {
         Self::from_raw((high as u64) << HIGH_SHIFT)
     }
     
+    /// Creates a new [DualHashKey] from a non-zero high-half, leaving the low-half zeroed out.
+    /// 
+    /// Since the high-half is non-zero, the DHK is non-zero too, so this method cannot fail.
+    #[inline(always)]
+    pub const fn from_nonzero_high(high: core::num::NonZeroU32) -> Self {
+        Self::from_nonzero_halves(high, 0)
+    }
+    
+    /// Creates a new [DualHashKey] from a non-zero high-half and a raw low-half.
+    /// 
+    /// Since the high-half is non-zero, the DHK is non-zero too, so this method cannot fail.
+    #[inline(always)]
+    pub const fn from_nonzero_halves(high: core::num::NonZeroU32, low: u32) -> Self {
+        // # Safety
+        // The high-half is a `NonZeroU32`, so at least one of its bits is set.
+        // As such, the raw DHK **cannot** be zero, so no check is needed.
+        unsafe {
+            Self::from_raw_unchecked((high.get() as u64) << HIGH_SHIFT | (low as u64))
+        }
+    }
+    
     /// Safely creates a new [DualHashKey] from a raw [u64] value.
     #[inline(always)]
     pub const fn from_raw(hash: u64) -> Option<Self> {
@@ -124,6 +597,41 @@ impl DualHashKey {
         }
     }
     
+    /// Safely creates a new [DualHashKey] from the raw big-endian bytes.
+    #[inline(always)]
+    pub const fn from_be_bytes(bytes: [u8; 8]) -> Option<Self> {
+        Self::from_raw(u64::from_be_bytes(bytes))
+    }
+    
+    /// Safely creates a new [DualHashKey] from the raw little-endian bytes.
+    #[inline(always)]
+    pub const fn from_le_bytes(bytes: [u8; 8]) -> Option<Self> {
+        Self::from_raw(u64::from_le_bytes(bytes))
+    }
+    
+    /// Safely creates a new [DualHashKey] from exactly 8 raw big-endian bytes.
+    /// 
+    /// Returns `None` if `bytes` is not exactly 8 bytes long, or if they are all zero.
+    /// Unlike the `TryFrom<&[u8]>` implementation, the bytes are *not* hashed.
+    #[inline(always)]
+    pub const fn from_raw_slice(bytes: &[u8]) -> Option<Self> {
+        match bytes {
+            &[a, b, c, d, e, f, g, h] => Self::from_be_bytes([a, b, c, d, e, f, g, h]),
+            _ => None,
+        }
+    }
+    
+    /// Safely creates a new [DualHashKey] from a raw [u64] value.
+    /// 
+    /// Same as [Self::from_raw], but with a typed error for `?`-propagation.
+    #[inline(always)]
+    pub const fn try_new(hash: u64) -> Result<Self, DhkError> {
+        match Self::from_raw(hash) {
+            Some(key) => Ok(key),
+            None => Err(DhkError::ZeroRaw),
+        }
+    }
+    
     /// Directly creates a new [DualHashKey] from a raw [u64] value.
     /// 
     /// # Safety
@@ -133,12 +641,23 @@ impl DualHashKey {
         Self {hash: NonZeroU64::new_unchecked(hash)}
     }
     
+    /// Rechecks the non-zero invariant of this key, as a belt-and-suspenders step
+    /// after constructing it via [Self::from_raw_unchecked] or some other unsafe path
+    /// (e.g. a custom deserializer that bypasses the checked constructors).
+    /// 
+    /// This is a best-effort debugging aid: a zero key is already undefined behaviour,
+    /// so the compiler is allowed to assume that this check always passes.
+    #[inline(always)]
+    pub const fn validated(self) -> Result<Self, DhkError> {
+        Self::try_new(self.get_hash_raw())
+    }
+    
     /// Swaps the low and high halfes.
     #[inline(always)]
     pub const fn swapped(&self) -> Option<Self> {
         Self::from_raw_dual(
-            self.get_hash_high_half(), 
-            self.get_hash_low_half()
+            self.get_hash_low_half(), 
+            self.get_hash_high_half()
         )
     }
     
@@ -154,6 +673,47 @@ impl DualHashKey {
         Self::from_raw((self.hash.get() & HIGH_MASK) | (low as u64) )
     }
     
+    /// Creates a copy with the given half replaced.
+    #[inline(always)]
+    pub const fn with_half(&self, which: Half, value: u32) -> Option<Self> {
+        match which {
+            Half::High => self.with_high_half_raw(value),
+            Half::Low => self.with_low_half_raw(value),
+        }
+    }
+    
+    /// Applies the fallible function `f` to the high-half and then the low-half,
+    /// creating a new [DualHashKey] from the results.
+    /// 
+    /// Returns the first error returned by `f`, leaving the other half unvisited;
+    /// or `Ok(None)` if the resulting key is zero.
+    pub fn try_map_halves<E>(&self, f: impl Fn(u32) -> Result<u32, E>) -> Result<Option<Self>, E> {
+        let high = f(self.get_hash_high_half())?;
+        let low = f(self.get_hash_low_half())?;
+        Ok(Self::from_raw_dual(high, low))
+    }
+    
+    /// Creates a copy with the high-half set to the integer `n`, as-is.
+    /// 
+    /// For parents that are indexed by integer, rather than by hashed string.
+    #[inline(always)]
+    pub const fn with_high_half_counter(&self, n: u32) -> Option<Self> {
+        self.with_high_half_raw(n)
+    }
+    
+    /// Creates a copy with the low-half set to the integer `offset`, as-is,
+    /// indexing the children of this key like an array.
+    #[inline(always)]
+    pub const fn child_at_offset(&self, offset: u32) -> Option<Self> {
+        self.with_low_half_raw(offset)
+    }
+    
+    /// Gets the integer stored in the high-half by [Self::with_high_half_counter].
+    #[inline(always)]
+    pub const fn high_half_counter(&self) -> u32 {
+        self.get_hash_high_half()
+    }
+    
     /// Gets the wrapped hash value.
     #[inline(always)]
     pub const fn get_hash(&self) -> NonZeroU64 {
@@ -180,6 +740,39 @@ impl DualHashKey {
         (self.get_hash_raw() & LOW_MASK) as u32
     }
     
+    /// Gets the given half of the hash.
+    #[inline(always)]
+    pub const fn get_half(&self, which: Half) -> u32 {
+        match which {
+            Half::High => self.get_hash_high_half(),
+            Half::Low => self.get_hash_low_half(),
+        }
+    }
+    
+    /// Gets the raw value as big-endian bytes.
+    #[inline(always)]
+    pub const fn to_be_bytes(&self) -> [u8; 8] {
+        self.get_hash_raw().to_be_bytes()
+    }
+    
+    /// Gets the raw value as little-endian bytes.
+    #[inline(always)]
+    pub const fn to_le_bytes(&self) -> [u8; 8] {
+        self.get_hash_raw().to_le_bytes()
+    }
+    
+    /// Gets the high-half of the hash as big-endian bytes.
+    #[inline(always)]
+    pub const fn high_bytes_be(&self) -> [u8; 4] {
+        self.get_hash_high_half().to_be_bytes()
+    }
+    
+    /// Gets the low-half of the hash as big-endian bytes.
+    #[inline(always)]
+    pub const fn low_bytes_be(&self) -> [u8; 4] {
+        self.get_hash_low_half().to_be_bytes()
+    }
+    
     /// Checks if the low-half of the hash has any of its bits set.
     #[inline(always)]
     pub const fn is_hash_low_half_set(&self) -> bool {
@@ -192,12 +785,41 @@ impl DualHashKey {
         self.get_hash_low_half() == 0
     }
     
+    /// Checks if the subtree of this key can be walked, i.e. if the high-half is non-zero.
+    /// 
+    /// Only then is [Self::get_hash_low_half_min] guaranteed to return `Some`,
+    /// so that the range between it and [Self::get_hash_low_half_max] covers the whole subtree.
+    /// Keys with a zero high-half should be rejected when the hierarchy matters.
+    #[inline(always)]
+    pub const fn is_walkable(&self) -> bool {
+        self.get_hash_high_half() != 0
+    }
+    
     /// Returns the hash with the low-half cleared.
     #[inline(always)]
     pub const fn get_hash_low_half_min(&self) -> Option<Self> {
         Self::from_raw(self.get_hash_raw() & HIGH_MASK)
     }
     
+    /// Returns the hash with the low-half cleared, or the `fallback` if that would be zero.
+    #[inline(always)]
+    pub const fn low_half_min_or(&self, fallback: Self) -> Self {
+        match self.get_hash_low_half_min() {
+            Some(min) => min,
+            None => fallback,
+        }
+    }
+    
+    /// Returns the hash with the low-half cleared, or [DhkError::ZeroRaw] if that would be zero
+    /// (i.e. if the high-half is zero).
+    #[inline(always)]
+    pub const fn try_low_half_min(&self) -> Result<Self, DhkError> {
+        match self.get_hash_low_half_min() {
+            Some(min) => Ok(min),
+            None => Err(DhkError::ZeroRaw),
+        }
+    }
+    
     /// Returns the hash with the low-half filled.
     /// 
     /// Since the low-half is filled with bits, making the DHK non-zero, this method cannot fail.
@@ -210,26 +832,344 @@ impl DualHashKey {
             Self::from_raw_unchecked(self.get_hash_raw() | LOW_MASK)
         }
     }
+    
+    /// Returns the raw hash with the low-half cleared, which may be zero.
+    #[inline(always)]
+    pub const fn get_hash_low_half_min_raw(&self) -> u64 {
+        self.get_hash_raw() & HIGH_MASK
+    }
+    
+    /// Returns the raw hash with the low-half filled.
+    #[inline(always)]
+    pub const fn get_hash_low_half_max_raw(&self) -> u64 {
+        self.get_hash_raw() | LOW_MASK
+    }
+    
+    /// Returns the position of this key within the whole key-space, as a value in `0.0..=1.0`.
+    /// 
+    /// Intended for UI placement (timelines, scrollbars, etc.), not for ordering:
+    /// an [f64] only has 53 bits of mantissa, so distinct keys that are close together
+    /// (especially large ones) will map to the same position.
+    pub fn normalized_position(&self) -> f64 {
+        self.get_hash_raw() as f64 / u64::MAX as f64
+    }
+    
+    /// Returns the high and low halves as a pair of [f64]s, for export into numeric tools.
+    /// 
+    /// Unlike [Self::normalized_position], this is exact: every [u32] fits into the mantissa of an [f64].
+    pub fn to_u32_pair_f64(&self) -> (f64, f64) {
+        (self.get_hash_high_half() as f64, self.get_hash_low_half() as f64)
+    }
+    
+    /// Returns the signed difference between the raw values of `self` and `other`.
+    /// 
+    /// Widens to [i128], so this cannot overflow.
+    #[inline(always)]
+    pub const fn signed_diff(&self, other: &Self) -> i128 {
+        self.get_hash_raw() as i128 - other.get_hash_raw() as i128
+    }
+    
+    /// Compares the raw values of both keys; same as [Ord::cmp], but usable in `const` contexts.
+    #[inline(always)]
+    pub const fn const_cmp(&self, other: &Self) -> core::cmp::Ordering {
+        let (a, b) = (self.get_hash_raw(), other.get_hash_raw());
+        if a < b {
+            core::cmp::Ordering::Less
+        } else if a > b {
+            core::cmp::Ordering::Greater
+        } else {
+            core::cmp::Ordering::Equal
+        }
+    }
+    
+    /// Checks if both keys are equal; same as [PartialEq::eq], but usable in `const` contexts.
+    #[inline(always)]
+    pub const fn const_eq(&self, other: &Self) -> bool {
+        self.get_hash_raw() == other.get_hash_raw()
+    }
+    
+    /// Checks if two keys have the same raw value, i.e. if they collide
+    /// (when built from different strings); for `const` guards over key tables.
+    #[inline(always)]
+    pub const fn raw_collides(a: Self, b: Self) -> bool {
+        a.const_eq(&b)
+    }
+    
+    /// Returns the lower of both keys; same as [Ord::min], but usable in `const` contexts.
+    #[inline(always)]
+    pub const fn const_min(a: Self, b: Self) -> Self {
+        if a.get_hash_raw() <= b.get_hash_raw() { a } else { b }
+    }
+    
+    /// Returns the higher of both keys; same as [Ord::max], but usable in `const` contexts.
+    #[inline(always)]
+    pub const fn const_max(a: Self, b: Self) -> Self {
+        if a.get_hash_raw() > b.get_hash_raw() { a } else { b }
+    }
+    
+    /// Returns the immediately-greater key in raw order, or `None` at [MAX].
+    #[inline(always)]
+    pub const fn next(&self) -> Option<Self> {
+        match self.get_hash_raw().checked_add(1) {
+            Some(raw) => Self::from_raw(raw),
+            None => None,
+        }
+    }
+    
+    /// Returns the immediately-lesser key in raw order, or `None` at [MIN].
+    #[inline(always)]
+    pub const fn prev(&self) -> Option<Self> {
+        // The raw value is non-zero, so this cannot underflow; it can only reach zero.
+        Self::from_raw(self.get_hash_raw() - 1)
+    }
+    
+    /// Returns the key at the fraction `i / n` of the raw span between `lo` and `hi`,
+    /// i.e. the starting key of the `i`-th of `n` equally-sized partitions.
+    /// 
+    /// Returns `None` if `n` is zero, `i` is greater than `n`, or `lo` is greater than `hi`.
+    pub const fn nth_partition_start(lo: Self, hi: Self, i: usize, n: usize) -> Option<Self> {
+        if n == 0 || i > n || lo.get_hash_raw() > hi.get_hash_raw() {
+            return None;
+        }
+        let span = (hi.get_hash_raw() - lo.get_hash_raw()) as u128;
+        let offset = span * i as u128 / n as u128;
+        Self::from_raw(lo.get_hash_raw() + offset as u64)
+    }
+    
+    /// Returns the number of possible keys in the inclusive range between `lo` and `hi`.
+    /// 
+    /// Returns zero if the bounds are reversed (`lo` greater than `hi`), like an empty range.
+    #[inline(always)]
+    pub const fn range_cardinality(lo: &Self, hi: &Self) -> u64 {
+        match hi.get_hash_raw().checked_sub(lo.get_hash_raw()) {
+            Some(span) => span.saturating_add(1),
+            None => 0,
+        }
+    }
+    
+    /// Returns the Hamming distance (number of differing bits) between the high-halves of both keys.
+    /// 
+    /// This is a corruption-tolerance aid for stored keys (e.g. accepting a high-half with a single flipped bit),
+    /// *not* a hierarchy feature: hashes of similar parents are not any closer in Hamming distance.
+    #[inline(always)]
+    pub const fn high_half_hamming(&self, other: &Self) -> u32 {
+        (self.get_hash_high_half() ^ other.get_hash_high_half()).count_ones()
+    }
+    
+    /// Iterates over the positions (`0..64`, from least to most significant) of the set bits in the raw value.
+    pub fn set_bit_positions(&self) -> impl Iterator<Item = u32> {
+        let mut bits = self.get_hash_raw();
+        core::iter::from_fn(move || {
+            if bits == 0 {
+                return None;
+            }
+            let position = bits.trailing_zeros();
+            bits &= bits - 1;
+            Some(position)
+        })
+    }
+}
+
+/// Functions/Methods for mutating the DHK in-place.
+/// 
+/// Each of these only commits the new value if it is non-zero;
+/// otherwise the key is left untouched and an error is returned.
+impl DualHashKey {
+    /// Replaces the high-half in-place.
+    #[inline(always)]
+    pub fn set_high_half_raw(&mut self, high: u32) -> Result<(), DhkError> {
+        *self = self.with_high_half_raw(high).ok_or(DhkError::ZeroRaw)?;
+        Ok(())
+    }
+    
+    /// Replaces the low-half in-place.
+    #[inline(always)]
+    pub fn set_low_half_raw(&mut self, low: u32) -> Result<(), DhkError> {
+        *self = self.with_low_half_raw(low).ok_or(DhkError::ZeroRaw)?;
+        Ok(())
+    }
+    
+    /// Replaces the high-half in-place.
+    pub fn set_high_half_str(&mut self, high: &str) -> Result<(), DhkError> {
+        self.set_high_half_raw(hash::fnv1a_32(high.as_bytes(), hash::FNV_OFFSET_BASIS_32))
+    }
+    
+    /// Replaces the low-half in-place.
+    pub fn set_low_half_str(&mut self, low: &str) -> Result<(), DhkError> {
+        self.set_low_half_raw(hash::fnv1a_32(low.as_bytes(), hash::FNV_OFFSET_BASIS_32))
+    }
+    
+    /// Swaps the low and high halfes in-place.
+    /// 
+    /// Swapping the halves of a non-zero key cannot make it zero, so this cannot fail.
+    #[inline(always)]
+    pub fn swap(&mut self) {
+        // # Safety
+        // Rotating the raw DHK only moves its bits around, so it **cannot** become zero.
+        *self = unsafe { Self::from_raw_unchecked(self.get_hash_raw().rotate_left(HIGH_SHIFT as u32)) };
+    }
+}
+
+/// Functions/Methods for the subtree of the DHK.
+impl DualHashKey {
+    /// Returns `count` keys sharing the high-half of this key,
+    /// with their low-halves spread evenly across `0..=u32::MAX`.
+    /// 
+    /// The keys are returned in ascending order; the first has its low-half cleared,
+    /// the last has its low-half filled. If the high-half is zero,
+    /// the key with the cleared low-half would be zero and is skipped.
+    #[cfg(feature = "std")]
+    pub fn sample_subtree(&self, count: usize) -> Vec<Self> {
+        let steps = count.saturating_sub(1).max(1) as u128;
+        (0..count)
+            .map(|i| (i as u128 * LOW_MASK as u128 / steps) as u32)
+            .filter_map(|low| self.with_low_half_raw(low))
+            .collect()
+    }
+    
+    /// Iterates over the canonical ancestor chain of this key:
+    /// the parent-node key (with the low-half cleared), followed by this key itself.
+    /// 
+    /// The parent-node key is skipped if it would be zero, or if it *is* this key.
+    pub fn ancestors(&self) -> impl Iterator<Item = DualHashKey> {
+        let parent = self.get_hash_low_half_min().filter(|parent| parent != self);
+        parent.into_iter().chain(core::iter::once(*self))
+    }
+    
+    /// Checks if the `other` key falls within the subtree range of this key,
+    /// i.e. between `self.get_hash_low_half_min()` and `self.get_hash_low_half_max()` inclusive.
+    /// 
+    /// Both bounds are inclusive, so this is the same as checking if both keys share the high-half.
+    #[inline(always)]
+    pub const fn subtree_contains(&self, other: &Self) -> bool {
+        self.contains(other)
+    }
+    
+    /// Checks if the `other` key lies within the subtree of this key,
+    /// treating this key as the prefix of the subtree.
+    /// 
+    /// This is the same as [Self::shares_high_half], phrased as containment.
+    #[inline(always)]
+    pub const fn contains(&self, other: &Self) -> bool {
+        let raw = other.get_hash_raw();
+        self.get_hash_low_half_min_raw() <= raw && raw <= self.get_hash_low_half_max_raw()
+    }
+    
+    /// Checks if both keys share the same high-half.
+    /// 
+    /// Like all comparisons of the halves, this is necessary-but-not-sufficient:
+    /// two different parents can hash to the same high-half.
+    #[inline(always)]
+    pub const fn shares_high_half(&self, other: &Self) -> bool {
+        self.get_hash_high_half() == other.get_hash_high_half()
+    }
+    
+    /// Checks if this key is the parent of the `other` key,
+    /// i.e. if this key has its low-half cleared and both share the same high-half.
+    /// 
+    /// Like all comparisons of the halves, this is necessary-but-not-sufficient:
+    /// two different parents can hash to the same high-half.
+    #[inline(always)]
+    pub const fn is_parent_of(&self, other: &Self) -> bool {
+        self.is_hash_low_half_clear() && self.shares_high_half(other)
+    }
+    
+    /// Checks if this key is a child of the `other` key; the inverse of [Self::is_parent_of].
+    #[inline(always)]
+    pub const fn is_child_of(&self, other: &Self) -> bool {
+        other.is_parent_of(self)
+    }
+    
+    /// Returns the inclusive range between this key and the `other` key,
+    /// with the lower of both keys as start, regardless of the argument order.
+    /// 
+    /// This prevents accidentally-empty ranges from reversed bounds.
+    #[inline(always)]
+    pub const fn range_to(&self, other: &Self) -> core::ops::RangeInclusive<Self> {
+        if self.get_hash_raw() <= other.get_hash_raw() {
+            core::ops::RangeInclusive::new(*self, *other)
+        } else {
+            core::ops::RangeInclusive::new(*other, *self)
+        }
+    }
+    
+    /// Clamps this key into the inclusive range between `lo` and `hi`, by raw value.
+    /// 
+    /// Intended for sanitizing cursors; same as [Ord::clamp], except that
+    /// reversed bounds only trip a debug assertion instead of always panicking.
+    #[inline(always)]
+    pub const fn clamp_range(&self, lo: Self, hi: Self) -> Self {
+        debug_assert!(lo.get_hash_raw() <= hi.get_hash_raw(), "lower bound of DHK range is above upper bound");
+        if self.get_hash_raw() < lo.get_hash_raw() {
+            lo
+        } else if self.get_hash_raw() > hi.get_hash_raw() {
+            hi
+        } else {
+            *self
+        }
+    }
+    
+    /// Returns the big-endian encodings of the lowest and highest keys in the subtree of this key,
+    /// for use as byte-slice range bounds in key-value stores.
+    /// 
+    /// Big-endian byte order sorts the same as the raw value,
+    /// so the encodings of all keys in the subtree fall within these bounds.
+    #[inline(always)]
+    pub const fn subtree_byte_bounds(&self) -> ([u8; 8], [u8; 8]) {
+        (
+            (self.get_hash_raw() & HIGH_MASK).to_be_bytes(),
+            (self.get_hash_raw() | LOW_MASK).to_be_bytes()
+        )
+    }
 }
 
 impl core::convert::TryFrom<u64> for DualHashKey {
-    type Error = &'static str;
+    type Error = DhkError;
     fn try_from(value: u64) -> Result<Self, Self::Error> {
-        Self::from_raw(value).ok_or("value given to DHK::from_raw is zero")
+        Self::try_new(value)
     }
 }
 
 impl core::convert::TryFrom<&[u8]> for DualHashKey {
-    type Error = &'static str;
+    type Error = DhkError;
     fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        Self::from_high_bytes(value).ok_or("generated hash of high-half bytes is zero")
+        Self::from_high_bytes(value).ok_or(DhkError::ZeroHighHash)
     }
 }
 
 impl core::convert::TryFrom<&str> for DualHashKey {
-    type Error = &'static str;
+    type Error = DhkError;
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        Self::from_high_str(value).ok_or("generated hash of high-half string is zero")
+        Self::from_high_str(value).ok_or(DhkError::ZeroHighHash)
+    }
+}
+
+/// Creates a [DualHashKey] from raw bytes in *native* endianness.
+/// 
+/// Use [DualHashKey::from_be_bytes] or [DualHashKey::from_le_bytes] for a fixed byte order.
+impl core::convert::TryFrom<[u8; 8]> for DualHashKey {
+    type Error = DhkError;
+    fn try_from(value: [u8; 8]) -> Result<Self, Self::Error> {
+        Self::try_new(u64::from_ne_bytes(value))
+    }
+}
+
+/// Creates a [DualHashKey] from a `[high, low]` pair of raw halves.
+/// 
+/// Index 0 is the high-half, index 1 is the low-half.
+impl core::convert::TryFrom<[u32; 2]> for DualHashKey {
+    type Error = DhkError;
+    fn try_from(value: [u32; 2]) -> Result<Self, Self::Error> {
+        Self::from_raw_dual(value[0], value[1]).ok_or(DhkError::ZeroRaw)
+    }
+}
+
+/// Splits a [DualHashKey] into a `[high, low]` pair of raw halves.
+/// 
+/// Index 0 is the high-half, index 1 is the low-half.
+impl core::convert::From<DualHashKey> for [u32; 2] {
+    fn from(key: DualHashKey) -> Self {
+        [key.get_hash_high_half(), key.get_hash_low_half()]
     }
 }
 
@@ -238,3 +1178,80 @@ impl core::convert::From<NonZeroU64> for DualHashKey {
         Self { hash }
     }
 }
+
+/// The default [DualHashKey] is [MIN], the lowest representable key.
+/// 
+/// Note that this is *not* a "null" key: a zero [DualHashKey] does not exist,
+/// so the default is indistinguishable from an actual key with the raw value `1`.
+impl Default for DualHashKey {
+    fn default() -> Self {
+        MIN
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DhkError, DualHashKey};
+    
+    const ROOT_LEAF: DualHashKey = DualHashKey::from_dual_str_const("root", "leaf");
+    
+    #[test]
+    fn const_binding_matches_runtime() {
+        assert_eq!(Some(ROOT_LEAF), DualHashKey::from_dual_str("root", "leaf"));
+    }
+    
+    // Both of these strings hash to zero under 32-bit FNV-1a.
+    const ZERO_A: &str = "akhnp9x";
+    const ZERO_B: &str = "b0ccrhg";
+    
+    #[test]
+    fn diagnosed_reports_zero_halves() {
+        assert_eq!(DualHashKey::fnv32(ZERO_A.as_bytes()), 0);
+        assert_eq!(DualHashKey::fnv32(ZERO_B.as_bytes()), 0);
+    
+        assert_eq!(DualHashKey::from_dual_str_diagnosed(ZERO_A, "leaf"), Err(DhkError::ZeroHighHash));
+        assert_eq!(DualHashKey::from_dual_str_diagnosed("root", ZERO_B), Err(DhkError::ZeroLowHash));
+        assert_eq!(DualHashKey::from_dual_str_diagnosed(ZERO_A, ZERO_B), Err(DhkError::ZeroRaw));
+        assert_eq!(DualHashKey::from_dual_str_diagnosed("root", "leaf").ok(), DualHashKey::from_dual_str("root", "leaf"));
+    }
+}
+
+#[cfg(all(test, feature = "rkyv", feature = "std"))]
+mod rkyv_tests {
+    use rkyv::rancor::Error;
+    use rkyv::vec::ArchivedVec;
+    
+    use crate::{ArchivedDualHashKey, DualHashKey};
+    
+    fn sorted_keys() -> Vec<DualHashKey> {
+        let mut keys: Vec<DualHashKey> = ["a", "b", "c", "d"].iter()
+            .flat_map(|high| ["x", "y", "z"].map(|low| DualHashKey::from_dual_str(high, low).unwrap()))
+            .collect();
+        keys.sort();
+        keys
+    }
+    
+    #[test]
+    fn archived_vec_preserves_order() {
+        let keys = sorted_keys();
+        let bytes = rkyv::to_bytes::<Error>(&keys).unwrap();
+        let archived = rkyv::access::<ArchivedVec<ArchivedDualHashKey>, Error>(&bytes).unwrap();
+    
+        assert_eq!(archived.len(), keys.len());
+        assert!(archived.windows(2).all(|pair| pair[0] < pair[1]));
+        for (archived, key) in archived.iter().zip(&keys) {
+            assert_eq!(archived, key);
+        }
+    }
+    
+    #[test]
+    fn zeroed_entry_fails_validation() {
+        let keys = sorted_keys();
+        let mut bytes = rkyv::to_bytes::<Error>(&keys).unwrap();
+        let needle = keys[5].get_hash_raw().to_le_bytes();
+        let offset = bytes.windows(8).position(|window| window == needle).unwrap();
+        bytes[offset..offset + 8].fill(0);
+    
+        assert!(rkyv::access::<ArchivedVec<ArchivedDualHashKey>, Error>(&bytes).is_err());
+    }
+}
@@ -0,0 +1,29 @@
+//! Functions for working with [BTreeMap]s keyed by [DualHashKey]s.
+
+use std::collections::BTreeMap;
+
+use crate::DualHashKey;
+
+/// Functions for maps keyed by DHKs.
+impl DualHashKey {
+    /// Replaces the entries of `dst` in the subtree of the `parent` with the entries of `src` in that same subtree.
+    /// 
+    /// Entries of `dst` outside of the subtree are left untouched,
+    /// and entries of `src` outside of the subtree are dropped.
+    pub fn merge_subtree<V>(dst: &mut BTreeMap<DualHashKey, V>, src: BTreeMap<DualHashKey, V>, parent: DualHashKey) {
+        let stale: Vec<DualHashKey> = dst.range(parent.subtree_range()).map(|(key, _)| *key).collect();
+        for key in stale {
+            dst.remove(&key);
+        }
+        dst.extend(src.into_iter().filter(|(key, _)| parent.subtree_contains(key)));
+    }
+    
+    /// Returns the smallest and largest keys present in the subtree of the `parent`,
+    /// or `None` if the subtree is empty.
+    pub fn subtree_endpoints<V>(map: &BTreeMap<DualHashKey, V>, parent: DualHashKey) -> Option<(&DualHashKey, &DualHashKey)> {
+        let mut range = map.range(parent.subtree_range());
+        let (first, _) = range.next()?;
+        let last = range.next_back().map_or(first, |(key, _)| key);
+        Some((first, last))
+    }
+}
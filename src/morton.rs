@@ -0,0 +1,62 @@
+//! Morton (Z-order) codes of [DualHashKey]s.
+//! 
+//! A Morton code interleaves the bits of both halves:
+//! bit `i` of the low-half becomes bit `2i` of the code,
+//! and bit `i` of the high-half becomes bit `2i + 1` of the code.
+//! 
+//! This gives a locality-preserving ordering that treats both halves equally,
+//! which is *different* from the hierarchical ordering of the keys themselves.
+
+use crate::DualHashKey;
+
+/// Spreads the 32 bits of `x` into the even bits of a [u64].
+const fn spread(x: u32) -> u64 {
+    let mut x = x as u64;
+    x = (x | (x << 16)) & 0x0000_FFFF_0000_FFFF;
+    x = (x | (x << 8)) & 0x00FF_00FF_00FF_00FF;
+    x = (x | (x << 4)) & 0x0F0F_0F0F_0F0F_0F0F;
+    x = (x | (x << 2)) & 0x3333_3333_3333_3333;
+    x = (x | (x << 1)) & 0x5555_5555_5555_5555;
+    x
+}
+
+/// Gathers the even bits of `x` into a [u32]; the inverse of [spread].
+const fn gather(x: u64) -> u32 {
+    let mut x = x & 0x5555_5555_5555_5555;
+    x = (x | (x >> 1)) & 0x3333_3333_3333_3333;
+    x = (x | (x >> 2)) & 0x0F0F_0F0F_0F0F_0F0F;
+    x = (x | (x >> 4)) & 0x00FF_00FF_00FF_00FF;
+    x = (x | (x >> 8)) & 0x0000_FFFF_0000_FFFF;
+    x = (x | (x >> 16)) & 0x0000_0000_FFFF_FFFF;
+    x as u32
+}
+
+/// Functions/Methods for the Morton form of the DHK.
+impl DualHashKey {
+    /// Returns the Morton code of this key, interleaving the bits of the high-half and low-half.
+    /// 
+    /// Bit `i` of the low-half becomes bit `2i` of the code,
+    /// and bit `i` of the high-half becomes bit `2i + 1` of the code.
+    /// Note that the Morton ordering is different from the hierarchical ordering of the keys.
+    #[inline(always)]
+    pub const fn morton_code(&self) -> u64 {
+        (spread(self.get_hash_high_half()) << 1) | spread(self.get_hash_low_half())
+    }
+    
+    /// Creates a new [DualHashKey] from a Morton code; the inverse of [Self::morton_code].
+    #[inline(always)]
+    pub const fn from_morton(code: u64) -> Option<Self> {
+        Self::from_raw_dual(gather(code >> 1), gather(code))
+    }
+    
+    /// Returns the range of Morton codes that covers all keys sharing the high-half of this key.
+    /// 
+    /// Since the Morton ordering differs from the hierarchical ordering,
+    /// this range also contains the codes of keys with *other* high-halves;
+    /// it bounds the subtree, but is not exclusive to it.
+    #[inline(always)]
+    pub const fn morton_subtree_range(&self) -> core::ops::RangeInclusive<u64> {
+        let high = spread(self.get_hash_high_half()) << 1;
+        core::ops::RangeInclusive::new(high, high | spread(u32::MAX))
+    }
+}
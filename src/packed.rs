@@ -0,0 +1,62 @@
+//! A C-compatible layout of the halves of a [DualHashKey].
+
+use crate::{DhkError, DualHashKey};
+
+/// The halves of a [DualHashKey] as a `#[repr(C)]` struct, for interop with C bitfield layouts.
+/// 
+/// The `low` field comes first, followed by the `high` field;
+/// on a little-endian target, only the size and field order match the raw [u64] (big-endian targets differ).
+/// 
+/// Do *not* reinterpret one as the other: a `PackedDhk` is only 4-byte aligned, where a [DualHashKey] is 8-byte aligned,
+/// and an all-zero `PackedDhk` (such as its [Default]) is not a valid [DualHashKey].
+/// Convert via the [From] and [TryFrom] implementations instead.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct PackedDhk {
+    pub low: u32,
+    pub high: u32
+}
+
+impl core::convert::From<DualHashKey> for PackedDhk {
+    fn from(key: DualHashKey) -> Self {
+        Self {
+            low: key.get_hash_low_half(),
+            high: key.get_hash_high_half()
+        }
+    }
+}
+
+impl core::convert::TryFrom<PackedDhk> for DualHashKey {
+    type Error = DhkError;
+    fn try_from(value: PackedDhk) -> Result<Self, Self::Error> {
+        Self::from_raw_dual(value.high, value.low).ok_or(DhkError::ZeroRaw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PackedDhk;
+    use crate::{DhkError, DualHashKey};
+    
+    #[test]
+    fn fields_match_halves() {
+        let key = DualHashKey::from_raw_dual(0x11223344, 0x55667788).unwrap();
+        let packed = PackedDhk::from(key);
+        assert_eq!((packed.high, packed.low), (0x11223344, 0x55667788));
+        assert_eq!(DualHashKey::try_from(packed), Ok(key));
+        assert_eq!(DualHashKey::try_from(PackedDhk::default()), Err(DhkError::ZeroRaw));
+    }
+    
+    #[test]
+    #[cfg(target_endian = "little")]
+    fn field_order_matches_raw_bytes_on_little_endian() {
+        let key = DualHashKey::from_raw_dual(0x11223344, 0x55667788).unwrap();
+        let packed = PackedDhk::from(key);
+        let mut bytes = [0u8; 8];
+        bytes[..4].copy_from_slice(&packed.low.to_ne_bytes());
+        bytes[4..].copy_from_slice(&packed.high.to_ne_bytes());
+        assert_eq!(bytes, key.get_hash_raw().to_ne_bytes());
+        assert_eq!(core::mem::offset_of!(PackedDhk, low), 0);
+        assert_eq!(core::mem::offset_of!(PackedDhk, high), 4);
+    }
+}
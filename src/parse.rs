@@ -0,0 +1,83 @@
+//! Parsing [DualHashKey]s from text.
+
+use crate::{DhkError, DualHashKey, SEPARATOR};
+
+/// Functions/Methods for parsing the DHK.
+impl DualHashKey {
+    /// Parses a raw value from a string: `0x`-prefixed hexadecimal, `0b`-prefixed binary, or plain decimal.
+    /// 
+    /// Returns `None` if the string cannot be parsed, or if the raw value is zero.
+    pub fn from_numeric_str(s: &str) -> Option<Self> {
+        let (digits, radix) = if let Some(hex) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+            (hex, 16)
+        } else if let Some(bin) = s.strip_prefix("0b").or_else(|| s.strip_prefix("0B")) {
+            (bin, 2)
+        } else {
+            (s, 10)
+        };
+        // `from_str_radix` accepts a leading `+`, so the digits have to be checked first.
+        if !digits.chars().all(|digit| digit.is_digit(radix)) {
+            return None;
+        }
+        Self::from_raw(u64::from_str_radix(digits, radix).ok()?)
+    }
+}
+
+/// The separators accepted between the high-half and low-half when parsing a DHK.
+const SEPARATORS: [char; 3] = [SEPARATOR, ':', '-'];
+
+/// Parses a single half of the textual form of a DHK.
+fn parse_half(half: &str) -> Option<u32> {
+    if half.bytes().all(|digit| digit.is_ascii_hexdigit()) {
+        u32::from_str_radix(half, 16).ok()
+    } else {
+        None
+    }
+}
+
+/// Parses the `HIGH.LOW` form written by the [Display](core::fmt::Display) implementation,
+/// accepting both upper and lower case hex digits.
+/// 
+/// Besides the `.`, a `:` or `-` is also accepted as separator, as written by other tools.
+impl core::str::FromStr for DualHashKey {
+    type Err = DhkError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (high, low) = s.split_once(SEPARATORS).ok_or(DhkError::MissingSeparator)?;
+        let high = parse_half(high).ok_or(DhkError::InvalidHighHex)?;
+        let low = parse_half(low).ok_or(DhkError::InvalidLowHex)?;
+        Self::from_raw_dual(high, low).ok_or(DhkError::ZeroRaw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::DualHashKey;
+    
+    fn raw(s: &str) -> Option<u64> {
+        DualHashKey::from_numeric_str(s).map(|key| key.get_hash_raw())
+    }
+    
+    #[test]
+    fn numeric_str_parses_each_base() {
+        assert_eq!(raw("0xFF"), Some(255));
+        assert_eq!(raw("0Xff"), Some(255));
+        assert_eq!(raw("0b101"), Some(5));
+        assert_eq!(raw("0B101"), Some(5));
+        assert_eq!(raw("42"), Some(42));
+        assert_eq!(raw("0xFFFFFFFFFFFFFFFF"), Some(u64::MAX));
+    }
+    
+    #[test]
+    fn numeric_str_rejects_signs_and_empty_digits() {
+        for s in ["+7", "-7", "0x+ff", "0x-ff", "0b+1", "", "0x", "0b", " 7", "0b102", "0xfg"] {
+            assert_eq!(raw(s), None, "{s:?}");
+        }
+    }
+    
+    #[test]
+    fn numeric_str_rejects_zero_and_overflow() {
+        assert_eq!(raw("0"), None);
+        assert_eq!(raw("0x0"), None);
+        assert_eq!(raw("0x10000000000000000"), None);
+    }
+}
@@ -0,0 +1,24 @@
+//! Hashing of [Path]s, without lossy UTF-8 conversion.
+
+use std::path::Path;
+
+use crate::DualHashKey;
+
+/// Functions/Methods for the path form of the DHK.
+impl DualHashKey {
+    /// Creates a new [DualHashKey] from a filesystem path,
+    /// with the parent path as the high-half and the full path as the low-half.
+    /// 
+    /// Both are hashed via their [OsStr::as_encoded_bytes](std::ffi::OsStr::as_encoded_bytes),
+    /// so non-UTF-8 components are kept as-is.
+    /// 
+    /// If the path has no parent (i.e. it is a root or empty),
+    /// the full path is the high-half and the low-half is cleared.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let full = path.as_os_str().as_encoded_bytes();
+        match path.parent() {
+            Some(parent) => Self::from_dual_bytes(parent.as_os_str().as_encoded_bytes(), full),
+            None => Self::from_high_bytes(full),
+        }
+    }
+}
@@ -0,0 +1,23 @@
+//! `proptest` strategies for generating [DualHashKey]s.
+
+use ::proptest::prelude::*;
+
+use crate::DualHashKey;
+
+/// Generates any valid [DualHashKey].
+pub fn any_dual_hash_key() -> impl Strategy<Value = DualHashKey> {
+    (1..=u64::MAX).prop_map(|raw| DualHashKey::from_raw(raw).expect("raw value is non-zero"))
+}
+
+/// Generates a parent key and a child key in its subtree.
+/// 
+/// The parent has a non-zero high-half and its low-half cleared,
+/// the child shares that high-half and has a non-zero low-half;
+/// so `parent.is_parent_of(&child)` always holds.
+pub fn parent_child_pair() -> impl Strategy<Value = (DualHashKey, DualHashKey)> {
+    (1..=u32::MAX, 1..=u32::MAX).prop_map(|(high, low)| {
+        let parent = DualHashKey::from_raw_high(high).expect("high-half is non-zero");
+        let child = parent.with_low_half_raw(low).expect("high-half is non-zero");
+        (parent, child)
+    })
+}
@@ -0,0 +1,84 @@
+//! Ranges covering the subtree of a [DualHashKey].
+
+use core::ops::{Bound, RangeBounds, RangeInclusive};
+
+use crate::{DualHashKey, MIN};
+
+/// Functions/Methods for the subtree range of the DHK.
+impl DualHashKey {
+    /// Returns the inclusive range covering all keys sharing the high-half of this key,
+    /// for walking the subtree: `map.range(key.subtree_range())`.
+    /// 
+    /// If the high-half is zero, the lower bound is clamped to [MIN],
+    /// since a key with both halves cleared cannot exist.
+    #[inline(always)]
+    pub const fn subtree_range(&self) -> RangeInclusive<DualHashKey> {
+        let min = match self.get_hash_low_half_min() {
+            Some(min) => min,
+            None => MIN,
+        };
+        RangeInclusive::new(min, self.get_hash_low_half_max())
+    }
+    
+    /// Returns the inclusive start of the subtree of this key: the key with the low-half cleared.
+    /// 
+    /// Like [Self::subtree_range], this is clamped to [MIN] if the high-half is zero.
+    #[inline(always)]
+    pub const fn subtree_range_start(&self) -> Bound<DualHashKey> {
+        Bound::Included(*self.subtree_range().start())
+    }
+    
+    /// Returns the exclusive end of the subtree of this key: the first key of the *next* high-half,
+    /// or [Bound::Unbounded] if the high-half is already [u32::MAX].
+    /// 
+    /// Pairs with [Self::subtree_range_start] for half-open queries:
+    /// `map.range((key.subtree_range_start(), key.subtree_range_end()))`.
+    #[inline(always)]
+    pub const fn subtree_range_end(&self) -> Bound<DualHashKey> {
+        match self.get_hash_high_half().checked_add(1) {
+            Some(high) => match DualHashKey::from_raw_high(high) {
+                Some(next) => Bound::Excluded(next),
+                None => Bound::Unbounded,
+            },
+            None => Bound::Unbounded,
+        }
+    }
+}
+
+/// The inclusive bounds of all keys sharing the high-half of a parent key,
+/// usable wherever a range is expected: `map.range(SubtreeBound::new(parent))`.
+/// 
+/// The bounds have to be stored (rather than just the parent),
+/// since [RangeBounds] hands out references to them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SubtreeBound {
+    min: DualHashKey,
+    max: DualHashKey
+}
+
+impl SubtreeBound {
+    /// Creates the bounds of the subtree of the `parent`.
+    pub const fn new(parent: DualHashKey) -> Self {
+        let range = parent.subtree_range();
+        Self {
+            min: *range.start(),
+            max: *range.end()
+        }
+    }
+}
+
+impl From<DualHashKey> for SubtreeBound {
+    fn from(parent: DualHashKey) -> Self {
+        Self::new(parent)
+    }
+}
+
+impl RangeBounds<DualHashKey> for SubtreeBound {
+    fn start_bound(&self) -> Bound<&DualHashKey> {
+        Bound::Included(&self.min)
+    }
+    
+    fn end_bound(&self) -> Bound<&DualHashKey> {
+        Bound::Included(&self.max)
+    }
+}
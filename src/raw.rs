@@ -0,0 +1,116 @@
+//! A raw, possibly-zero companion of [DualHashKey], for assembling keys in stages.
+
+use crate::{hash, DualHashKey, HIGH_MASK, HIGH_SHIFT, LOW_MASK};
+
+/// The raw value of a [DualHashKey] that may still be zero.
+/// 
+/// All bit-manipulation methods are infallible;
+/// the non-zero check is done once at the end, via [Self::finish].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct RawDualHashKey(pub u64);
+
+impl RawDualHashKey {
+    /// Creates a new [RawDualHashKey] from two raw [u32] values.
+    #[inline(always)]
+    pub const fn from_raw_dual(high: u32, low: u32) -> Self {
+        Self((high as u64) << HIGH_SHIFT | (low as u64))
+    }
+    
+    /// Creates a new [RawDualHashKey] from a raw [u32] value for the high-half,
+    /// leaving the low-half zeroed out.
+    #[inline(always)]
+    pub const fn from_raw_high(high: u32) -> Self {
+        Self((high as u64) << HIGH_SHIFT)
+    }
+    
+    /// Creates a new [RawDualHashKey] from the pair of high and low strings.
+    pub const fn from_dual_str(high: &str, low: &str) -> Self {
+        Self::from_raw_dual(
+            hash::fnv1a_32(high.as_bytes(), hash::FNV_OFFSET_BASIS_32),
+            hash::fnv1a_32(low.as_bytes(), hash::FNV_OFFSET_BASIS_32)
+        )
+    }
+    
+    /// Creates a new [RawDualHashKey] from the pair of high and low sequences of bytes.
+    pub const fn from_dual_bytes(high: &[u8], low: &[u8]) -> Self {
+        Self::from_raw_dual(
+            hash::fnv1a_32(high, hash::FNV_OFFSET_BASIS_32),
+            hash::fnv1a_32(low, hash::FNV_OFFSET_BASIS_32)
+        )
+    }
+    
+    /// Checks the raw value, returning the finished [DualHashKey] if it is non-zero.
+    #[inline(always)]
+    pub const fn finish(self) -> Option<DualHashKey> {
+        DualHashKey::from_raw(self.0)
+    }
+    
+    /// Creates a copy with the high-half replaced.
+    #[inline(always)]
+    pub const fn with_high_half_raw(&self, high: u32) -> Self {
+        Self((self.0 & LOW_MASK) | ((high as u64) << HIGH_SHIFT))
+    }
+    
+    /// Creates a copy with the low-half replaced.
+    #[inline(always)]
+    pub const fn with_low_half_raw(&self, low: u32) -> Self {
+        Self((self.0 & HIGH_MASK) | (low as u64))
+    }
+    
+    /// Creates a copy with the high-half replaced.
+    pub const fn with_high_half_str(&self, high: &str) -> Self {
+        self.with_high_half_raw(hash::fnv1a_32(high.as_bytes(), hash::FNV_OFFSET_BASIS_32))
+    }
+    
+    /// Creates a copy with the low-half replaced.
+    pub const fn with_low_half_str(&self, low: &str) -> Self {
+        self.with_low_half_raw(hash::fnv1a_32(low.as_bytes(), hash::FNV_OFFSET_BASIS_32))
+    }
+    
+    /// Creates a copy with the high-half replaced.
+    pub const fn with_high_half_bytes(&self, high: &[u8]) -> Self {
+        self.with_high_half_raw(hash::fnv1a_32(high, hash::FNV_OFFSET_BASIS_32))
+    }
+    
+    /// Creates a copy with the low-half replaced.
+    pub const fn with_low_half_bytes(&self, low: &[u8]) -> Self {
+        self.with_low_half_raw(hash::fnv1a_32(low, hash::FNV_OFFSET_BASIS_32))
+    }
+    
+    /// Swaps the low and high halfes.
+    #[inline(always)]
+    pub const fn swapped(&self) -> Self {
+        Self(self.0.rotate_left(HIGH_SHIFT as u32))
+    }
+    
+    /// Gets the high-half of the raw value.
+    #[inline(always)]
+    pub const fn get_hash_high_half(&self) -> u32 {
+        (self.0 >> HIGH_SHIFT) as u32
+    }
+    
+    /// Gets the low-half of the raw value.
+    #[inline(always)]
+    pub const fn get_hash_low_half(&self) -> u32 {
+        (self.0 & LOW_MASK) as u32
+    }
+    
+    /// Returns the raw value with the low-half cleared.
+    #[inline(always)]
+    pub const fn get_hash_low_half_min_raw(&self) -> u64 {
+        self.0 & HIGH_MASK
+    }
+    
+    /// Returns the raw value with the low-half filled.
+    #[inline(always)]
+    pub const fn get_hash_low_half_max_raw(&self) -> u64 {
+        self.0 | LOW_MASK
+    }
+}
+
+impl core::convert::From<DualHashKey> for RawDualHashKey {
+    fn from(key: DualHashKey) -> Self {
+        Self(key.get_hash_raw())
+    }
+}
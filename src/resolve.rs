@@ -0,0 +1,27 @@
+//! Turning opaque [DualHashKey]s back into readable paths.
+
+use crate::DualHashKey;
+
+/// A lookup from hashed halves back to the strings they were created from.
+/// 
+/// The hashes are lossy, so this has to be backed by a side table
+/// that records the source strings as keys are created.
+pub trait PathResolver {
+    /// Returns the string whose hash is the given high-half, if known.
+    fn resolve_high(&self, high: u32) -> Option<&str>;
+    
+    /// Returns the string whose hash is the given low-half, if known.
+    fn resolve_low(&self, low: u32) -> Option<&str>;
+}
+
+impl DualHashKey {
+    /// Renders this key as `high/low`, using the `resolver` to look up the source strings of both halves.
+    /// 
+    /// Returns `None` if either half cannot be resolved.
+    #[cfg(feature = "std")]
+    pub fn display_path(&self, resolver: &impl PathResolver) -> Option<String> {
+        let high = resolver.resolve_high(self.get_hash_high_half())?;
+        let low = resolver.resolve_low(self.get_hash_low_half())?;
+        Some(format!("{high}/{low}"))
+    }
+}
@@ -0,0 +1,66 @@
+//! [Serialize] and [Deserialize] implementations for [DualHashKey].
+//! 
+//! Human-readable formats use the `HIGH.LOW` hex string, binary formats use the raw [u64].
+
+use ::serde::de::{self, Deserialize, Deserializer, Unexpected, Visitor};
+use ::serde::ser::{Serialize, Serializer};
+
+use crate::DualHashKey;
+
+impl Serialize for DualHashKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_u64(self.get_hash_raw())
+        }
+    }
+}
+
+/// Accepts either the `HIGH.LOW` hex string or the raw [u64], rejecting zero.
+struct DualHashKeyVisitor;
+
+impl Visitor<'_> for DualHashKeyVisitor {
+    type Value = DualHashKey;
+    
+    fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str("a non-zero DHK, as `HIGH.LOW` hex string or raw u64")
+    }
+    
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        DualHashKey::from_raw(v).ok_or_else(|| E::invalid_value(Unexpected::Unsigned(v), &self))
+    }
+    
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(E::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for DualHashKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(DualHashKeyVisitor)
+        } else {
+            deserializer.deserialize_u64(DualHashKeyVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::DualHashKey;
+    
+    #[test]
+    fn ron_uses_dotted_hex_string() {
+        let key = DualHashKey::stable_from_dual_str("root/mid/low", "root/mid/low/name").unwrap();
+        let text = ron::to_string(&key).unwrap();
+        assert_eq!(text, "\"E05F2E55.0CB0216D\"");
+        assert_eq!(ron::from_str::<DualHashKey>(&text).unwrap(), key);
+    }
+    
+    #[test]
+    fn ron_rejects_invalid_strings() {
+        assert!(ron::from_str::<DualHashKey>("\"00000000.00000000\"").is_err());
+        assert!(ron::from_str::<DualHashKey>("\"E05F2E550CB0216D\"").is_err());
+    }
+}
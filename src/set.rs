@@ -0,0 +1,100 @@
+//! A set of [DualHashKey]s with hierarchical operations.
+
+use std::collections::BTreeSet;
+
+use crate::DualHashKey;
+
+/// An ordered set of [DualHashKey]s, wrapping a [BTreeSet].
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct DhkSet {
+    pub keys: BTreeSet<DualHashKey>
+}
+
+impl core::fmt::Debug for DhkSet {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.keys.iter()).finish()
+    }
+}
+
+impl DhkSet {
+    /// Creates a new empty [DhkSet].
+    pub const fn new() -> Self {
+        Self {keys: BTreeSet::new()}
+    }
+    
+    /// Returns the number of keys in the set.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+    
+    /// Returns `true` if the set contains no keys.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+    
+    /// Adds a key to the set, returning whether it was newly inserted.
+    pub fn insert(&mut self, key: DualHashKey) -> bool {
+        self.keys.insert(key)
+    }
+    
+    /// Adds the key for the pair of high and low strings to the set,
+    /// returning whether it was newly inserted.
+    /// 
+    /// Returns `None` if the generated key is zero.
+    pub fn insert_str(&mut self, high: &str, low: &str) -> Option<bool> {
+        DualHashKey::from_dual_str(high, low).map(|key| self.insert(key))
+    }
+    
+    /// Checks if the set contains the given key.
+    pub fn contains(&self, key: &DualHashKey) -> bool {
+        self.keys.contains(key)
+    }
+    
+    /// Removes a key from the set, returning whether it was present.
+    pub fn remove(&mut self, key: &DualHashKey) -> bool {
+        self.keys.remove(key)
+    }
+    
+    /// Iterates over all keys in ascending order.
+    pub fn iter(&self) -> std::collections::btree_set::Iter<'_, DualHashKey> {
+        self.keys.iter()
+    }
+    
+    /// Iterates over all keys sharing the high-half of the `parent`, in ascending order.
+    pub fn iter_subtree(&self, parent: DualHashKey) -> std::collections::btree_set::Range<'_, DualHashKey> {
+        self.keys.range(parent.subtree_range())
+    }
+    
+    /// Creates a new set containing only the keys sharing the high-half of the `parent`.
+    pub fn subtree(&self, parent: DualHashKey) -> Self {
+        self.iter_subtree(parent).copied().collect()
+    }
+}
+
+impl FromIterator<DualHashKey> for DhkSet {
+    fn from_iter<I: IntoIterator<Item = DualHashKey>>(iter: I) -> Self {
+        Self {keys: BTreeSet::from_iter(iter)}
+    }
+}
+
+impl Extend<DualHashKey> for DhkSet {
+    fn extend<I: IntoIterator<Item = DualHashKey>>(&mut self, iter: I) {
+        self.keys.extend(iter)
+    }
+}
+
+impl<'a> IntoIterator for &'a DhkSet {
+    type Item = &'a DualHashKey;
+    type IntoIter = std::collections::btree_set::Iter<'a, DualHashKey>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.keys.iter()
+    }
+}
+
+impl IntoIterator for DhkSet {
+    type Item = DualHashKey;
+    type IntoIter = std::collections::btree_set::IntoIter<DualHashKey>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.keys.into_iter()
+    }
+}
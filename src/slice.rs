@@ -0,0 +1,132 @@
+//! Functions for working with sorted slices of [DualHashKey]s.
+
+use crate::DualHashKey;
+
+/// Returns the sub-slice of a sorted slice that contains the keys sharing the high-half of the `parent`.
+fn subtree_of<'a>(sorted: &'a [DualHashKey], parent: &DualHashKey) -> &'a [DualHashKey] {
+    let start = sorted.partition_point(|key| key.get_hash_high_half() < parent.get_hash_high_half());
+    let end = sorted.partition_point(|key| key.get_hash_high_half() <= parent.get_hash_high_half());
+    &sorted[start..end]
+}
+
+/// Functions for sorted slices of DHKs.
+impl DualHashKey {
+    /// Groups a sorted slice of keys into contiguous per-parent chunks,
+    /// each tagged with the high-half the keys in it share.
+    /// 
+    /// The chunks borrow from `sorted`, so no per-group vectors are allocated.
+    /// If `sorted` is not sorted, keys of the same parent may end up in several chunks.
+    #[cfg(feature = "std")]
+    pub fn group_by_subtree(sorted: &[DualHashKey]) -> Vec<(u32, &[DualHashKey])> {
+        sorted
+            .chunk_by(|a, b| a.get_hash_high_half() == b.get_hash_high_half())
+            .map(|chunk| (chunk[0].get_hash_high_half(), chunk))
+            .collect()
+    }
+    
+    /// Returns the distinct high-halves of a sorted slice of keys, in order.
+    /// 
+    /// This is a single pass over `sorted`, relying on keys of the same parent being adjacent.
+    #[cfg(feature = "std")]
+    pub fn distinct_parents(sorted: &[DualHashKey]) -> Vec<u32> {
+        sorted
+            .chunk_by(|a, b| a.get_hash_high_half() == b.get_hash_high_half())
+            .map(|chunk| chunk[0].get_hash_high_half())
+            .collect()
+    }
+    
+    /// Returns the indices of all zero values in a slice of raw values,
+    /// i.e. those that [DualHashKey::from_raw] would reject.
+    #[cfg(feature = "std")]
+    pub fn invalid_indices(raws: &[u64]) -> Vec<usize> {
+        raws.iter()
+            .enumerate()
+            .filter(|(_, raw)| **raw == 0)
+            .map(|(index, _)| index)
+            .collect()
+    }
+    
+    /// Distributes keys across `shards` shards, by their high-half modulo `shards`.
+    /// 
+    /// All keys sharing a high-half land in the same shard, so no subtree is split.
+    /// The keys keep their relative order within each shard.
+    /// 
+    /// # Panics
+    /// Panics if `shards` is zero.
+    #[cfg(feature = "std")]
+    pub fn shard_keys(keys: &[DualHashKey], shards: usize) -> Vec<Vec<DualHashKey>> {
+        assert!(shards != 0, "number of shards must be non-zero");
+        let mut out = vec![Vec::new(); shards];
+        for key in keys {
+            out[key.get_hash_high_half() as usize % shards].push(*key);
+        }
+        out
+    }
+    
+    /// Returns the smallest key in a sorted slice that is strictly greater than this key,
+    /// for advancing a cursor over a materialized list of keys.
+    pub fn next_present(&self, sorted: &[DualHashKey]) -> Option<DualHashKey> {
+        let index = sorted.partition_point(|key| key <= self);
+        sorted.get(index).copied()
+    }
+    
+    /// Returns the fraction of the low-half space of the `parent` that is occupied in a sorted slice,
+    /// i.e. the number of keys sharing its high-half divided by `2^32`.
+    /// 
+    /// Useful for spotting parents whose children are approaching collision-prone density.
+    pub fn subtree_density(sorted: &[DualHashKey], parent: &DualHashKey) -> f64 {
+        subtree_of(sorted, parent).len() as f64 / (1u64 << 32) as f64
+    }
+    
+    /// Appends the 8 big-endian bytes of each key to `out`.
+    #[cfg(feature = "std")]
+    pub fn write_all_be(keys: &[DualHashKey], out: &mut Vec<u8>) {
+        out.reserve(keys.len() * 8);
+        for key in keys {
+            out.extend_from_slice(&key.get_hash_raw().to_be_bytes());
+        }
+    }
+    
+    /// Reads keys from a buffer of 8 big-endian bytes each; the inverse of [DualHashKey::write_all_be].
+    /// 
+    /// Returns `None` if the length of `bytes` is not a multiple of 8, or if any of the keys are zero.
+    #[cfg(feature = "std")]
+    pub fn read_all_be(bytes: &[u8]) -> Option<Vec<Self>> {
+        let chunks = bytes.chunks_exact(8);
+        if !chunks.remainder().is_empty() {
+            return None;
+        }
+        chunks.map(Self::from_raw_slice).collect()
+    }
+    
+    /// Run-length encodes the high-halves of a sorted slice of keys,
+    /// returning each distinct high-half along with the number of keys that share it.
+    #[cfg(feature = "std")]
+    pub fn rle_high_halves(sorted: &[DualHashKey]) -> Vec<(u32, usize)> {
+        sorted
+            .chunk_by(|a, b| a.get_hash_high_half() == b.get_hash_high_half())
+            .map(|chunk| (chunk[0].get_hash_high_half(), chunk.len()))
+            .collect()
+    }
+    
+    /// Checks if any key in a sorted slice shares the high-half of the `parent`,
+    /// using a single binary search.
+    pub fn subtree_exists(sorted: &[DualHashKey], parent: &DualHashKey) -> bool {
+        let index = sorted.partition_point(|key| key.get_hash_high_half() < parent.get_hash_high_half());
+        sorted.get(index).is_some_and(|key| key.shares_high_half(parent))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::DualHashKey;
+    
+    #[test]
+    fn distinct_parents_in_order() {
+        let keys: Vec<DualHashKey> = [(1, 1), (1, 2), (2, 1), (3, 1), (3, 2), (3, 3)].iter()
+            .map(|(high, low)| DualHashKey::from_raw_dual(*high, *low).unwrap())
+            .collect();
+        assert_eq!(DualHashKey::distinct_parents(&keys), [1, 2, 3]);
+        assert!(DualHashKey::distinct_parents(&[]).is_empty());
+    }
+}